@@ -8,6 +8,7 @@ mod async_drop;
 pub(crate) mod async_lock;
 pub use async_drop::*;
 pub(crate) mod file;
+pub(crate) mod timeout;
 
 // Not macOS-specific itself but only used on macOS.
 #[cfg(target_os = "macos")]
@@ -0,0 +1,26 @@
+use std::{future::Future, time::Duration};
+
+/// Run `future` to completion, unless `duration` elapses first.
+///
+/// Returns `None` on timeout. If the future completes at the same time as the timeout elapses,
+/// its output is returned.
+pub(crate) async fn timeout<F>(duration: Duration, future: F) -> Option<F::Output>
+where
+    F: Future,
+{
+    #[cfg(not(feature = "tokio"))]
+    {
+        use futures_util::future::{select, Either};
+
+        let future = std::pin::pin!(future);
+        match select(future, async_io::Timer::after(duration)).await {
+            Either::Left((output, _)) => Some(output),
+            Either::Right(_) => None,
+        }
+    }
+
+    #[cfg(feature = "tokio")]
+    {
+        tokio::time::timeout(duration, future).await.ok()
+    }
+}
@@ -22,6 +22,22 @@ use self::transport::Stream;
 pub use self::transport::Transport;
 
 /// A bus address.
+///
+/// The string representation of an address, as returned by its [`Display`] implementation, is
+/// the one expected in the `DBUS_SESSION_BUS_ADDRESS` and `DBUS_SYSTEM_BUS_ADDRESS` environment
+/// variables, and it can be parsed back into an `Address` through its [`FromStr`] implementation.
+/// This comes handy to launch child processes that should connect to a specific bus:
+///
+/// ```no_run
+/// # use std::process::Command;
+/// # use zbus::Address;
+/// let address: Address = "unix:path=/tmp/dbus-test,guid=0123456789abcdef0123456789abcdef"
+///     .parse()?;
+/// Command::new("my-client")
+///     .env("DBUS_SESSION_BUS_ADDRESS", address.to_string())
+///     .spawn()?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[non_exhaustive]
 pub struct Address {
@@ -412,6 +428,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn roundtrip_dbus_addresses() {
+        let guid = crate::Guid::generate();
+        let addresses = [
+            Address::from(Transport::Unix(Unix::new(UnixSocket::File(
+                "/tmp/dbus test,=%".into(),
+            )))),
+            Address::from(Transport::Unix(Unix::new(UnixSocket::TmpDir(
+                "/tmp".into(),
+            ))))
+            .set_guid(guid.clone())
+            .unwrap(),
+            Address::from(Transport::Tcp(
+                Tcp::new("localhost", 4142).set_family(Some(TcpTransportFamily::Ipv6)),
+            ))
+            .set_guid(guid)
+            .unwrap(),
+        ];
+        for address in addresses {
+            assert_eq!(Address::from_str(&address.to_string()).unwrap(), address);
+        }
+    }
+
     #[test]
     fn connect_tcp() {
         let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
@@ -1,13 +1,11 @@
-#[cfg(target_os = "linux")]
-use std::ffi::OsString;
 use std::{
-    ffi::OsStr,
+    ffi::{OsStr, OsString},
     fmt::{Display, Formatter},
     path::PathBuf,
 };
 
 #[cfg(unix)]
-use super::encode_percents;
+use super::{decode_percents, encode_percents};
 
 /// A Unix domain socket transport in a D-Bus address.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -37,17 +35,17 @@ impl Unix {
         let dir = opts.get("dir");
         let tmpdir = opts.get("tmpdir");
         let path = match (path, abs, dir, tmpdir) {
-            (Some(p), None, None, None) => UnixSocket::File(PathBuf::from(p)),
+            (Some(p), None, None, None) => UnixSocket::File(PathBuf::from(decode_unix_path(p)?)),
             #[cfg(target_os = "linux")]
-            (None, Some(p), None, None) => UnixSocket::Abstract(OsString::from(p)),
+            (None, Some(p), None, None) => UnixSocket::Abstract(decode_unix_path(p)?),
             #[cfg(not(target_os = "linux"))]
             (None, Some(_), None, None) => {
                 return Err(crate::Error::Address(
                     "abstract sockets currently Linux-only".to_owned(),
                 ));
             }
-            (None, None, Some(p), None) => UnixSocket::Dir(PathBuf::from(p)),
-            (None, None, None, Some(p)) => UnixSocket::TmpDir(PathBuf::from(p)),
+            (None, None, Some(p), None) => UnixSocket::Dir(PathBuf::from(decode_unix_path(p)?)),
+            (None, None, None, Some(p)) => UnixSocket::TmpDir(PathBuf::from(decode_unix_path(p)?)),
             _ => {
                 return Err(crate::Error::Address("unix: address is invalid".to_owned()));
             }
@@ -63,6 +61,19 @@ impl Display for Unix {
     }
 }
 
+fn decode_unix_path(value: &str) -> crate::Result<OsString> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStringExt;
+
+        decode_percents(value).map(OsString::from_vec)
+    }
+
+    // Paths are not percent-encoded on Windows, see `UnixSocket`'s `Display` impl.
+    #[cfg(windows)]
+    Ok(OsString::from(value))
+}
+
 /// A Unix domain socket path in a D-Bus address.
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[non_exhaustive]
@@ -3,6 +3,7 @@ use static_assertions::assert_impl_all;
 use std::net::TcpStream;
 #[cfg(all(unix, not(feature = "tokio")))]
 use std::os::unix::net::UnixStream;
+use std::time::Duration;
 #[cfg(feature = "tokio")]
 use tokio::net::TcpStream;
 #[cfg(all(unix, feature = "tokio"))]
@@ -179,6 +180,32 @@ impl<'a> Builder<'a> {
         Self(self.0.max_queued(max))
     }
 
+    /// Only accept method calls from peers running as the same user as this process.
+    ///
+    /// See [`crate::connection::Builder::peer_pid_check`] for details and caveats.
+    #[cfg(unix)]
+    pub fn peer_pid_check(self, enabled: bool) -> Self {
+        Self(self.0.peer_pid_check(enabled))
+    }
+
+    /// Set the maximum time to wait for the reply to a method call.
+    ///
+    /// See [`crate::connection::Builder::method_timeout`] for details.
+    pub fn method_timeout(self, timeout: Duration) -> Self {
+        Self(self.0.method_timeout(timeout))
+    }
+
+    /// Set a handler to be called for each incoming message, in the order they arrive.
+    ///
+    /// See [`crate::connection::Builder::inline_handler`] for details, including the reentrancy
+    /// constraints: in particular, the handler must not make blocking calls on the connection.
+    pub fn inline_handler<F>(self, handler: F) -> Self
+    where
+        F: FnMut(&crate::message::Message) + Send + Sync + 'static,
+    {
+        Self(self.0.inline_handler(handler))
+    }
+
     /// Register a D-Bus [`Interface`] to be served at a given path.
     ///
     /// This is similar to [`zbus::blocking::ObjectServer::at`], except that it allows you to have
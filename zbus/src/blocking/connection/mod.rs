@@ -3,7 +3,7 @@
 use enumflags2::BitFlags;
 use event_listener::EventListener;
 use static_assertions::assert_impl_all;
-use std::{io, ops::Deref};
+use std::{io, ops::Deref, time::Duration};
 use zbus_names::{BusName, ErrorName, InterfaceName, MemberName, OwnedUniqueName, WellKnownName};
 use zvariant::ObjectPath;
 
@@ -12,7 +12,7 @@ use crate::{
     fdo::{ConnectionCredentials, RequestNameFlags, RequestNameReply},
     message::Message,
     utils::block_on,
-    DBusError, Error, Result,
+    Address, DBusError, Error, Result,
 };
 
 mod builder;
@@ -21,6 +21,11 @@ pub use builder::Builder;
 /// A blocking wrapper of [`zbus::Connection`].
 ///
 /// Most of the API is very similar to [`zbus::Connection`], except it's blocking.
+///
+/// Just like [`zbus::Connection`], it's cheap to clone and can be shared between threads, e.g. the
+/// workers of a thread pool. Method calls made concurrently from different threads are all sent
+/// on the same socket and each of them only waits for its own reply, so there is no need for a
+/// connection per thread.
 #[derive(Debug, Clone)]
 #[must_use = "Dropping a `Connection` will close the underlying socket."]
 pub struct Connection {
@@ -40,6 +45,14 @@ impl Connection {
         block_on(crate::Connection::system()).map(Self::from)
     }
 
+    /// Create a `Connection` from a socket passed to this process through socket activation.
+    ///
+    /// See [`crate::Connection::from_activation_fds`] for details.
+    #[cfg(unix)]
+    pub fn from_activation_fds() -> Result<Option<Self>> {
+        block_on(crate::Connection::from_activation_fds()).map(|conn| conn.map(Self::from))
+    }
+
     /// The capacity of the main (unfiltered) queue.
     pub fn max_queued(&self) -> usize {
         self.inner.max_queued()
@@ -50,16 +63,51 @@ impl Connection {
         self.inner.set_max_queued(max)
     }
 
+    /// The address this connection was established to.
+    ///
+    /// See [`crate::Connection::address`] for details.
+    pub fn address(&self) -> Option<&Address> {
+        self.inner.address()
+    }
+
     /// The server's GUID.
     pub fn server_guid(&self) -> &str {
         self.inner.server_guid()
     }
 
+    /// The maximum time to wait for the reply to a method call, if any.
+    pub fn method_timeout(&self) -> Option<Duration> {
+        self.inner.method_timeout()
+    }
+
+    /// Whether passing of Unix file descriptors was agreed on with the peer.
+    ///
+    /// See [`crate::Connection::unix_fds_supported`] for details.
+    #[cfg(unix)]
+    pub fn unix_fds_supported(&self) -> bool {
+        self.inner.unix_fds_supported()
+    }
+
     /// The unique name as assigned by the message bus or `None` if not a message bus connection.
     pub fn unique_name(&self) -> Option<&OwnedUniqueName> {
         self.inner.unique_name()
     }
 
+    /// The I/O statistics of this connection.
+    ///
+    /// See [`crate::Connection::statistics`] for details.
+    #[cfg(feature = "statistics")]
+    pub fn statistics(&self) -> crate::connection::Statistics {
+        self.inner.statistics()
+    }
+
+    /// Get a handle to shut down this connection from anywhere, including other threads.
+    ///
+    /// See [`crate::Connection::shutdown_handle`] for details.
+    pub fn shutdown_handle(&self) -> crate::connection::ShutdownHandle {
+        self.inner.shutdown_handle()
+    }
+
     /// Send `msg` to the peer.
     pub fn send(&self, msg: &Message) -> Result<()> {
         block_on(self.inner.send(msg))
@@ -96,6 +144,40 @@ impl Connection {
         )
     }
 
+    /// Send a method call, with a timeout for this call only.
+    ///
+    /// See [`crate::Connection::call_method_with_timeout`] for details.
+    #[allow(clippy::too_many_arguments)]
+    pub fn call_method_with_timeout<'d, 'p, 'i, 'm, D, P, I, M, B>(
+        &self,
+        destination: Option<D>,
+        path: P,
+        iface: Option<I>,
+        method_name: M,
+        body: &B,
+        timeout: Duration,
+    ) -> Result<Message>
+    where
+        D: TryInto<BusName<'d>>,
+        P: TryInto<ObjectPath<'p>>,
+        I: TryInto<InterfaceName<'i>>,
+        M: TryInto<MemberName<'m>>,
+        D::Error: Into<Error>,
+        P::Error: Into<Error>,
+        I::Error: Into<Error>,
+        M::Error: Into<Error>,
+        B: serde::ser::Serialize + zvariant::DynamicType,
+    {
+        block_on(self.inner.call_method_with_timeout(
+            destination,
+            path,
+            iface,
+            method_name,
+            body,
+            timeout,
+        ))
+    }
+
     /// Emit a signal.
     ///
     /// Create a signal message, and send it over the connection.
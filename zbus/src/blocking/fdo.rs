@@ -17,3 +17,66 @@ pub use crate::fdo::{
     properties::{PropertiesChangedIterator, PropertiesProxyBlocking as PropertiesProxy},
     stats::StatsProxyBlocking as StatsProxy,
 };
+pub use crate::fdo::{DBusStatistics, Features, NameOwnerChanged};
+
+use futures_util::StreamExt;
+use static_assertions::assert_impl_all;
+use std::collections::{HashMap, HashSet};
+use zbus_names::{BusName, OwnedBusName, OwnedUniqueName, OwnedWellKnownName};
+
+use crate::{blocking::Connection, fdo::Result, utils::block_on};
+
+/// Get the features supported by the message bus `conn` is connected to.
+///
+/// See [`crate::fdo::get_supported_features`] for details.
+pub fn get_supported_features(conn: &Connection) -> Result<Vec<Features>> {
+    block_on(crate::fdo::get_supported_features(conn.inner()))
+}
+
+/// Get the statistics of the message bus `conn` is connected to.
+///
+/// See [`crate::fdo::get_stats`] for details.
+pub fn get_stats(conn: &Connection) -> Result<DBusStatistics> {
+    block_on(crate::fdo::get_stats(conn.inner()))
+}
+
+/// Get the owners of all the well-known names on the message bus `conn` is connected to.
+///
+/// See [`crate::fdo::get_name_owners`] for details.
+pub fn get_name_owners(conn: &Connection) -> Result<HashMap<OwnedWellKnownName, OwnedUniqueName>> {
+    block_on(crate::fdo::get_name_owners(conn.inner()))
+}
+
+/// Watch the ownership of any of `names`.
+///
+/// See [`crate::fdo::watch_name_owners`] for details.
+pub fn watch_name_owners<'n, I, N>(conn: &Connection, names: I) -> Result<NameOwnerWatchIterator>
+where
+    I: IntoIterator<Item = N>,
+    N: TryInto<BusName<'n>>,
+    N::Error: Into<crate::Error>,
+{
+    block_on(crate::fdo::watch_name_owners(conn.inner(), names)).map(NameOwnerWatchIterator)
+}
+
+/// An iterator of `NameOwnerChanged` signals about a set of names, as returned by
+/// [`watch_name_owners`].
+#[derive(Debug)]
+pub struct NameOwnerWatchIterator(crate::fdo::NameOwnerWatchStream);
+
+assert_impl_all!(NameOwnerWatchIterator: Send, Sync, Unpin);
+
+impl NameOwnerWatchIterator {
+    /// The names being watched.
+    pub fn names(&self) -> &HashSet<OwnedBusName> {
+        self.0.names()
+    }
+}
+
+impl Iterator for NameOwnerWatchIterator {
+    type Item = NameOwnerChanged;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        block_on(self.0.next())
+    }
+}
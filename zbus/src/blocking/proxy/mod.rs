@@ -3,7 +3,7 @@
 use enumflags2::BitFlags;
 use futures_util::StreamExt;
 use static_assertions::assert_impl_all;
-use std::{fmt, ops::Deref};
+use std::{fmt, ops::Deref, time::Duration};
 use zbus_names::{BusName, InterfaceName, MemberName, UniqueName};
 use zvariant::{ObjectPath, OwnedValue, Value};
 
@@ -12,7 +12,7 @@ use crate::{
     message::Message,
     proxy::{MethodFlags, ProxyDefault},
     utils::block_on,
-    Error, Result,
+    Error, Result, Task,
 };
 
 use crate::fdo;
@@ -157,6 +157,13 @@ impl<'a> Proxy<'a> {
         self.inner().interface()
     }
 
+    /// The maximum time to wait for the reply to a method call, if any.
+    ///
+    /// See [`crate::Proxy::method_timeout`] for details.
+    pub fn method_timeout(&self) -> Option<Duration> {
+        self.inner().method_timeout()
+    }
+
     /// Introspect the associated object, and return the XML description.
     ///
     /// See the [xml](https://docs.rs/zbus_xml) crate for parsing the result.
@@ -201,6 +208,16 @@ impl<'a> Proxy<'a> {
         block_on(self.inner().get_property(property_name))
     }
 
+    /// Get all the properties of the interface at once, deserialized into `T`.
+    ///
+    /// See [`crate::Proxy::get_all_properties`] for details.
+    pub fn get_all_properties<T>(&self) -> Result<T>
+    where
+        T: for<'d> zvariant::DynamicDeserialize<'d>,
+    {
+        block_on(self.inner().get_all_properties())
+    }
+
     /// Set the property `property_name`.
     ///
     /// Effectively, call the `Set` method of the `org.freedesktop.DBus.Properties` interface.
@@ -328,6 +345,18 @@ impl<'a> Proxy<'a> {
             .map(SignalIterator)
     }
 
+    /// Call `callback` for each signal named `signal_name`.
+    ///
+    /// See [`crate::Proxy::connect_signal`] for details.
+    pub fn connect_signal<'m, M, F>(&self, signal_name: M, callback: F) -> Result<Task<()>>
+    where
+        M: TryInto<MemberName<'m>>,
+        M::Error: Into<Error>,
+        F: FnMut(Message) + Send + 'static,
+    {
+        block_on(self.inner().connect_signal(signal_name, callback))
+    }
+
     /// Get an iterator to receive owner changed events.
     ///
     /// If the proxy destination is a unique name, the stream will be notified of the peer
@@ -559,4 +588,31 @@ mod tests {
         // one we subscribed to.
         assert!(signal.args().unwrap().name() == well_known);
     }
+
+    #[test]
+    #[timeout(15000)]
+    fn signal_iterator_adapters() {
+        let conn = Connection::session().unwrap();
+        let unique_name = conn.unique_name().unwrap().to_string();
+        let proxy = blocking::fdo::DBusProxy::new(&conn).unwrap();
+        let owner_changed = proxy
+            .receive_name_owner_changed_with_args(&[(2, unique_name.as_str())])
+            .unwrap();
+
+        let names = [
+            "org.freedesktop.zbus.SignalIteratorTest1",
+            "org.freedesktop.zbus.SignalIteratorTest2",
+            "org.freedesktop.zbus.SignalIteratorTest3",
+        ];
+        for name in names {
+            conn.request_name(name).unwrap();
+        }
+
+        // Signal iterators are regular iterators and compose as such.
+        let acquired: Vec<_> = owner_changed
+            .take(names.len())
+            .map(|signal| signal.args().unwrap().name().to_string())
+            .collect();
+        assert_eq!(acquired, names);
+    }
 }
@@ -8,6 +8,7 @@ use std::net::TcpStream;
 use std::os::unix::net::UnixStream;
 use std::{
     collections::{HashMap, HashSet, VecDeque},
+    time::Duration,
     vec,
 };
 #[cfg(feature = "tokio")]
@@ -25,14 +26,18 @@ use zvariant::{ObjectPath, Str};
 
 use crate::{
     address::{self, Address},
+    message::Message,
     names::{InterfaceName, WellKnownName},
     object_server::{ArcInterface, Interface},
     Connection, Error, Executor, Guid, OwnedGuid, Result,
 };
 
+#[cfg(unix)]
+use super::PeerUidCheck;
 use super::{
     handshake::{AuthMechanism, Authenticated},
     socket::{BoxedSplit, ReadHalf, Split, WriteHalf},
+    socket_reader::InlineHandler,
 };
 
 const DEFAULT_MAX_QUEUED: usize = 64;
@@ -60,11 +65,15 @@ type Interfaces<'a> = HashMap<ObjectPath<'a>, HashMap<InterfaceName<'static>, Ar
 pub struct Builder<'a> {
     target: Option<Target>,
     max_queued: Option<usize>,
+    method_timeout: Option<Duration>,
+    inline_handler: Option<InlineHandler>,
     // This is only set for p2p server case or pre-authenticated sockets.
     guid: Option<Guid<'a>>,
     #[cfg(feature = "p2p")]
     p2p: bool,
     internal_executor: bool,
+    #[cfg(unix)]
+    peer_pid_check: bool,
     interfaces: Interfaces<'a>,
     names: HashSet<WellKnownName<'a>>,
     auth_mechanisms: Option<VecDeque<AuthMechanism>>,
@@ -293,6 +302,52 @@ impl<'a> Builder<'a> {
         self
     }
 
+    /// Set the maximum time to wait for the reply to a method call.
+    ///
+    /// If no reply arrives in time, the call fails with an [`Error::InputOutput`] of kind
+    /// [`std::io::ErrorKind::TimedOut`] and the reply is discarded if it arrives later. This can be
+    /// overridden for each proxy, through [`crate::proxy::Builder::method_timeout`].
+    ///
+    /// By default, there is no timeout.
+    pub fn method_timeout(mut self, timeout: Duration) -> Self {
+        self.method_timeout = Some(timeout);
+
+        self
+    }
+
+    /// Set a handler to be called for each incoming message, in the order they arrive.
+    ///
+    /// Incoming messages are normally dispatched to a number of independent queues: each
+    /// [`MessageStream`], each pending method call and the [`ObjectServer`] get their own. While
+    /// each queue preserves the order of the messages it receives, nothing ensures that your code
+    /// observes messages from different queues in the order they were received. For example, a
+    /// signal announcing a property change may be handled after the reply to a method call that
+    /// was sent after it, making the reply look newer than it is.
+    ///
+    /// The handler is called with every message read from the socket, before the message is made
+    /// available to any queue. So by the time a method call returns, the handler has seen all the
+    /// messages that preceded its reply on the wire. This makes it a good place to keep state
+    /// that needs to be strictly ordered with respect to method replies, such as a cache of
+    /// property values updated from signals.
+    ///
+    /// # Reentrancy
+    ///
+    /// The handler runs inline in the task that reads from the socket, and no other messages are
+    /// read until it returns. It must therefore not block and must not wait for anything that
+    /// requires receiving messages on the same connection, such as the reply to a method call:
+    /// that would deadlock the connection.
+    ///
+    /// [`MessageStream`]: crate::MessageStream
+    /// [`ObjectServer`]: crate::ObjectServer
+    pub fn inline_handler<F>(mut self, handler: F) -> Self
+    where
+        F: FnMut(&Message) + Send + Sync + 'static,
+    {
+        self.inline_handler = Some(InlineHandler(Box::new(handler)));
+
+        self
+    }
+
     /// Enable or disable the internal executor thread.
     ///
     /// The thread is enabled by default.
@@ -304,6 +359,34 @@ impl<'a> Builder<'a> {
         self
     }
 
+    /// Only accept method calls from peers running as the same user as this process.
+    ///
+    /// When enabled, the [`ObjectServer`] checks the Unix user ID of the sender of each incoming
+    /// method call before dispatching it. On bus connections, the ID is looked up through the
+    /// `GetConnectionCredentials` method of the bus. On peer-to-peer connections, it's read from
+    /// the socket once, right after the handshake. Calls from other users, or from peers whose user
+    /// can't be determined, are rejected with `org.freedesktop.DBus.Error.AccessDenied`.
+    ///
+    /// This only applies to method calls dispatched by the [`ObjectServer`]. Messages received
+    /// through a [`MessageStream`] are not filtered. The check is disabled by default.
+    ///
+    /// # Caveats
+    ///
+    /// The credentials are those of the process that opened the peer's connection, as recorded
+    /// when it connected, while the check happens later, when a call is received. This is a
+    /// time-of-check to time-of-use (TOCTOU) gap: a process that changes its user after
+    /// connecting, or that hands its connection over to another process, is not detected.
+    /// Moreover, on a bus connection, each method call costs an additional round-trip to the bus.
+    ///
+    /// [`ObjectServer`]: crate::ObjectServer
+    /// [`MessageStream`]: crate::MessageStream
+    #[cfg(unix)]
+    pub fn peer_pid_check(mut self, enabled: bool) -> Self {
+        self.peer_pid_check = enabled;
+
+        self
+    }
+
     /// Register a D-Bus [`Interface`] to be served at a given path.
     ///
     /// This is similar to [`zbus::ObjectServer::at`], except that it allows you to have your
@@ -396,6 +479,10 @@ impl<'a> Builder<'a> {
         #[cfg(feature = "bus-impl")]
         let unique_name = self.unique_name.take().map(Into::into);
 
+        let address = match &self.target {
+            Some(Target::Address(address)) => Some(address.clone()),
+            _ => None,
+        };
         #[allow(unused_mut)]
         let (mut stream, server_guid, authenticated) = self.target_connect().await?;
         let mut auth = if authenticated {
@@ -457,7 +544,35 @@ impl<'a> Builder<'a> {
         #[cfg(unix)]
         let already_received_fds = auth.already_received_fds.drain(..).collect();
 
-        let mut conn = Connection::new(auth, is_bus_conn, executor).await?;
+        // Record the user of the peer now, rather than querying the socket for each method call.
+        #[cfg(unix)]
+        let peer_uid_check = match (self.peer_pid_check, is_bus_conn) {
+            (false, _) => None,
+            (true, true) => Some(PeerUidCheck::Bus),
+            (true, false) => {
+                let uid = match auth.socket_write.peer_credentials().await {
+                    Ok(creds) => creds.unix_user_id(),
+                    Err(e) => {
+                        tracing::debug!("Failed to get the credentials of the peer: {}", e);
+
+                        None
+                    }
+                };
+
+                Some(PeerUidCheck::Peer(uid))
+            }
+        };
+
+        let mut conn = Connection::new(
+            auth,
+            is_bus_conn,
+            executor,
+            self.method_timeout,
+            address,
+            #[cfg(unix)]
+            peer_uid_check,
+        )
+        .await?;
         conn.set_max_queued(self.max_queued.unwrap_or(DEFAULT_MAX_QUEUED));
 
         if !self.interfaces.is_empty() {
@@ -487,6 +602,7 @@ impl<'a> Builder<'a> {
             already_received_bytes,
             #[cfg(unix)]
             already_received_fds,
+            self.inline_handler,
         );
 
         for name in self.names {
@@ -502,8 +618,12 @@ impl<'a> Builder<'a> {
             #[cfg(feature = "p2p")]
             p2p: false,
             max_queued: None,
+            method_timeout: None,
+            inline_handler: None,
             guid: None,
             internal_executor: true,
+            #[cfg(unix)]
+            peer_pid_check: false,
             interfaces: HashMap::new(),
             names: HashSet::new(),
             auth_mechanisms: None,
@@ -314,4 +314,32 @@ mod tests {
             .unwrap();
         crate::utils::block_on(server.perform()).unwrap();
     }
+
+    #[test]
+    #[timeout(15000)]
+    fn client_rejected_mechanism() {
+        let (p0, mut p1) = create_async_socket_pair();
+        let guid = OwnedGuid::from(Guid::generate());
+        let mechanisms = vec![AuthMechanism::External, AuthMechanism::Anonymous].into();
+        let client = Client::new(p0.into(), Some(mechanisms), None, false);
+
+        // The client falls back to the next mechanism on `REJECTED`.
+        crate::utils::block_on(
+            p1.write_all(
+                format!("REJECTED ANONYMOUS\r\nOK {guid}\r\nAGREE_UNIX_FD\r\n").as_bytes(),
+            ),
+        )
+        .unwrap();
+        let client = crate::utils::block_on(client.perform()).unwrap();
+        assert_eq!(client.server_guid, guid);
+        assert!(client.cap_unix_fd);
+
+        // And gives up once it runs out of mechanisms.
+        let (p0, mut p1) = create_async_socket_pair();
+        let mechanisms = vec![AuthMechanism::External].into();
+        let client = Client::new(p0.into(), Some(mechanisms), None, false);
+        crate::utils::block_on(p1.write_all(b"REJECTED ANONYMOUS\r\n")).unwrap();
+        let err = crate::utils::block_on(client.perform()).unwrap_err();
+        assert!(matches!(err, Error::Handshake(_)), "{err:?}");
+    }
 }
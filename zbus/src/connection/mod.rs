@@ -12,6 +12,7 @@ use std::{
     pin::Pin,
     sync::{Arc, OnceLock, Weak},
     task::{Context, Poll},
+    time::{Duration, Instant},
 };
 use tracing::{debug, info_span, instrument, trace, trace_span, warn, Instrument};
 use zbus_names::{BusName, ErrorName, InterfaceName, MemberName, OwnedUniqueName, WellKnownName};
@@ -21,14 +22,15 @@ use futures_core::Future;
 use futures_util::StreamExt;
 
 use crate::{
+    abstractions::timeout::timeout,
     async_lock::{Mutex, Semaphore, SemaphorePermit},
     blocking,
     fdo::{self, ConnectionCredentials, RequestNameFlags, RequestNameReply},
     is_flatpak,
-    message::{Flags, Message, Type},
+    message::{Flags, Header, Message, Type},
     proxy::CacheProperties,
-    DBusError, Error, Executor, MatchRule, MessageStream, ObjectServer, OwnedGuid, OwnedMatchRule,
-    Result, Task,
+    Address, DBusError, Error, Executor, MatchRule, MessageStream, ObjectServer, OwnedGuid,
+    OwnedMatchRule, Result, Task,
 };
 
 mod builder;
@@ -38,7 +40,18 @@ pub mod socket;
 pub use socket::Socket;
 
 mod socket_reader;
-use socket_reader::SocketReader;
+use socket_reader::{InlineHandler, SocketReader};
+
+mod shutdown;
+pub use shutdown::ShutdownHandle;
+use shutdown::ShutdownState;
+
+#[cfg(feature = "statistics")]
+mod statistics;
+#[cfg(feature = "statistics")]
+pub use statistics::Statistics;
+#[cfg(feature = "statistics")]
+use statistics::StatisticsCounters;
 
 pub(crate) mod handshake;
 use handshake::Authenticated;
@@ -49,9 +62,13 @@ const DEFAULT_MAX_METHOD_RETURN_QUEUED: usize = 8;
 /// Inner state shared by Connection and WeakConnection
 #[derive(Debug)]
 pub(crate) struct ConnectionInner {
+    address: Option<Address>,
     server_guid: OwnedGuid,
+    method_timeout: Option<Duration>,
     #[cfg(unix)]
     cap_unix_fd: bool,
+    #[cfg(unix)]
+    peer_uid_check: Option<PeerUidCheck>,
     #[cfg(feature = "p2p")]
     bus_conn: bool,
     unique_name: OnceLock<OwnedUniqueName>,
@@ -59,6 +76,9 @@ pub(crate) struct ConnectionInner {
 
     activity_event: Arc<Event>,
     socket_write: Mutex<Box<dyn socket::WriteHalf>>,
+    shutdown: Arc<ShutdownState>,
+    #[cfg(feature = "statistics")]
+    statistics: Arc<StatisticsCounters>,
 
     // Our executor
     executor: Executor<'static>,
@@ -88,6 +108,16 @@ impl Drop for ConnectionInner {
     }
 }
 
+/// How to check the user of the callers, if enabled through [`Builder::peer_pid_check`].
+#[cfg(unix)]
+#[derive(Debug)]
+pub(crate) enum PeerUidCheck {
+    /// Ask the bus for the credentials of each caller.
+    Bus,
+    /// The user ID of the peer, as recorded right after the handshake.
+    Peer(Option<u32>),
+}
+
 type Subscriptions = HashMap<OwnedMatchRule, (u64, InactiveReceiver<Result<Message>>)>;
 
 pub(crate) type MsgBroadcaster = Broadcaster<Result<Message>>;
@@ -288,9 +318,26 @@ impl OrderedFuture for PendingMethodCall {
     }
 }
 
+impl PendingMethodCall {
+    /// Wait for the reply, failing if it doesn't arrive within `method_timeout` (if any).
+    pub(crate) async fn reply(self, method_timeout: Option<Duration>) -> Result<Message> {
+        match method_timeout {
+            Some(duration) => timeout(duration, self).await.unwrap_or_else(|| {
+                Err(Error::InputOutput(
+                    io::Error::new(ErrorKind::TimedOut, "method call timed out").into(),
+                ))
+            }),
+            None => self.await,
+        }
+    }
+}
+
 impl Connection {
     /// Send `msg` to the peer.
     pub async fn send(&self, msg: &Message) -> Result<()> {
+        if self.inner.shutdown.is_shut_down() {
+            return Err(Error::Shutdown);
+        }
         #[cfg(unix)]
         if !msg.data().fds().is_empty() && !self.inner.cap_unix_fd {
             return Err(Error::Unsupported);
@@ -299,7 +346,11 @@ impl Connection {
         self.inner.activity_event.notify(usize::MAX);
         let mut write = self.inner.socket_write.lock().await;
 
-        write.send_message(msg).await
+        let res = write.send_message(msg).await;
+        #[cfg(feature = "statistics")]
+        self.inner.statistics.sent(&res, msg.data().len());
+
+        res
     }
 
     /// Send a method call.
@@ -307,7 +358,15 @@ impl Connection {
     /// Create a method-call message, send it over the connection, then wait for the reply.
     ///
     /// On successful reply, an `Ok(Message)` is returned. On error, an `Err` is returned. D-Bus
-    /// error replies are returned as [`Error::MethodError`].
+    /// error replies are returned as [`Error::MethodError`]. If a [method timeout] is set and no
+    /// reply arrives in time, an [`Error::InputOutput`] of kind [`ErrorKind::TimedOut`] is
+    /// returned.
+    ///
+    /// Dropping the returned future before it completes cancels the call: the reply is discarded
+    /// whenever it arrives.
+    ///
+    /// [method timeout]: Connection::method_timeout
+    /// [`ErrorKind::TimedOut`]: std::io::ErrorKind::TimedOut
     pub async fn call_method<'d, 'p, 'i, 'm, D, P, I, M, B>(
         &self,
         destination: Option<D>,
@@ -337,6 +396,53 @@ impl Connection {
         )
         .await?
         .expect("no reply")
+        .reply(self.method_timeout())
+        .await
+    }
+
+    /// Send a method call, with a timeout for this call only.
+    ///
+    /// Same as [`Connection::call_method`], except that `timeout` is used instead of the
+    /// [method timeout] of the connection. The timeout starts before the call is sent and if no
+    /// reply arrives in time, an [`Error::InputOutput`] of kind [`ErrorKind::TimedOut`] is
+    /// returned.
+    ///
+    /// [method timeout]: Connection::method_timeout
+    /// [`ErrorKind::TimedOut`]: std::io::ErrorKind::TimedOut
+    #[allow(clippy::too_many_arguments)]
+    pub async fn call_method_with_timeout<'d, 'p, 'i, 'm, D, P, I, M, B>(
+        &self,
+        destination: Option<D>,
+        path: P,
+        interface: Option<I>,
+        method_name: M,
+        body: &B,
+        timeout: Duration,
+    ) -> Result<Message>
+    where
+        D: TryInto<BusName<'d>>,
+        P: TryInto<ObjectPath<'p>>,
+        I: TryInto<InterfaceName<'i>>,
+        M: TryInto<MemberName<'m>>,
+        D::Error: Into<Error>,
+        P::Error: Into<Error>,
+        I::Error: Into<Error>,
+        M::Error: Into<Error>,
+        B: serde::ser::Serialize + zvariant::DynamicType,
+    {
+        let start = Instant::now();
+        // The send itself isn't interrupted, as that would leave a partial message on the socket.
+        self.call_method_raw(
+            destination,
+            path,
+            interface,
+            method_name,
+            BitFlags::empty(),
+            body,
+        )
+        .await?
+        .expect("no reply")
+        .reply(Some(timeout.saturating_sub(start.elapsed())))
         .await
     }
 
@@ -838,11 +944,40 @@ impl Connection {
         self.inner.msg_receiver.clone().set_capacity(max);
     }
 
+    /// The address this connection was established to.
+    ///
+    /// This is `None` if the connection was built from an already connected socket or stream
+    /// rather than an address, e.g. through [`Builder::unix_stream`].
+    pub fn address(&self) -> Option<&Address> {
+        self.inner.address.as_ref()
+    }
+
     /// The server's GUID.
     pub fn server_guid(&self) -> &OwnedGuid {
         &self.inner.server_guid
     }
 
+    /// The maximum time to wait for the reply to a method call, if any.
+    ///
+    /// This is set through [`Builder::method_timeout`] and can be overridden for each proxy,
+    /// through [`crate::proxy::Builder::method_timeout`].
+    pub fn method_timeout(&self) -> Option<Duration> {
+        self.inner.method_timeout
+    }
+
+    /// Whether passing of Unix file descriptors was agreed on with the peer.
+    ///
+    /// File descriptor passing is negotiated during the authentication handshake, using the
+    /// `NEGOTIATE_UNIX_FD` command, if the underlying socket supports it. Sending a message that
+    /// contains file descriptors on a connection where this returns `false` fails with
+    /// [`Error::Unsupported`].
+    ///
+    /// This method is only available on Unix platforms.
+    #[cfg(unix)]
+    pub fn unix_fds_supported(&self) -> bool {
+        self.inner.cap_unix_fd
+    }
+
     /// The underlying executor.
     ///
     /// When a connection is built with internal_executor set to false, zbus will not spawn a
@@ -1127,6 +1262,9 @@ impl Connection {
         auth: Authenticated,
         #[allow(unused)] bus_connection: bool,
         executor: Executor<'static>,
+        method_timeout: Option<Duration>,
+        address: Option<Address>,
+        #[cfg(unix)] peer_uid_check: Option<PeerUidCheck>,
     ) -> Result<Self> {
         #[cfg(unix)]
         let cap_unix_fd = auth.cap_unix_fd;
@@ -1162,9 +1300,16 @@ impl Connection {
             inner: Arc::new(ConnectionInner {
                 activity_event: Arc::new(Event::new()),
                 socket_write: Mutex::new(auth.socket_write),
+                shutdown: Arc::new(ShutdownState::default()),
+                #[cfg(feature = "statistics")]
+                statistics: Arc::new(StatisticsCounters::default()),
+                address,
                 server_guid: auth.server_guid,
+                method_timeout,
                 #[cfg(unix)]
                 cap_unix_fd,
+                #[cfg(unix)]
+                peer_uid_check,
                 #[cfg(feature = "p2p")]
                 bus_conn: bus_connection,
                 unique_name: OnceLock::new(),
@@ -1198,6 +1343,34 @@ impl Connection {
         Builder::system()?.build().await
     }
 
+    /// Create a `Connection` from a socket passed to this process through socket activation.
+    ///
+    /// Service managers like systemd can pass already connected sockets to the services they
+    /// activate, through the `LISTEN_PID`, `LISTEN_FDS` and `LISTEN_FDNAMES` environment variables.
+    /// The socket named `dbus` is used, if there is one. Otherwise the first socket is used. Since
+    /// the socket is already connected, only the authentication handshake and the `Hello` call (for
+    /// bus connections) are performed.
+    ///
+    /// Returns `Ok(None)` if no sockets were passed to this process.
+    ///
+    /// The socket can only be taken once: only the first call in the process takes the ownership of
+    /// it and later calls return `Ok(None)`. This is similar to calling `sd_listen_fds(3)` with
+    /// `unset_environment` set, except that the environment is left untouched.
+    #[cfg(unix)]
+    pub async fn from_activation_fds() -> Result<Option<Self>> {
+        let stream = match activation_socket()? {
+            Some(stream) => stream,
+            None => return Ok(None),
+        };
+        #[cfg(feature = "tokio")]
+        let stream = {
+            stream.set_nonblocking(true)?;
+            tokio::net::UnixStream::from_std(stream)?
+        };
+
+        Builder::unix_stream(stream).build().await.map(Some)
+    }
+
     /// Return a listener, notified on various connection activity.
     ///
     /// This function is meant for the caller to implement idle or timeout on inactivity.
@@ -1222,6 +1395,45 @@ impl Connection {
             .await
     }
 
+    /// Check that the sender of a method call runs as the same user as us, if enabled through
+    /// [`Builder::peer_pid_check`].
+    #[cfg(unix)]
+    pub(crate) async fn check_peer_uid(&self, hdr: &Header<'_>) -> fdo::Result<()> {
+        let uid = match &self.inner.peer_uid_check {
+            None => return Ok(()),
+            Some(PeerUidCheck::Peer(uid)) => Ok(*uid),
+            // Call the bus directly: a proxy kept around on the connection would keep it alive.
+            Some(PeerUidCheck::Bus) => match hdr.sender() {
+                Some(sender) => self
+                    .call_method(
+                        Some("org.freedesktop.DBus"),
+                        "/org/freedesktop/DBus",
+                        Some("org.freedesktop.DBus"),
+                        "GetConnectionCredentials",
+                        sender,
+                    )
+                    .await
+                    .and_then(|reply| reply.body().deserialize::<fdo::ConnectionCredentials>())
+                    .map(|creds| creds.unix_user_id())
+                    .map_err(fdo::Error::from),
+                None => Ok(None),
+            },
+        };
+        let uid = uid.unwrap_or_else(|e| {
+            debug!("Failed to get the credentials of the caller: {}", e);
+
+            None
+        });
+        let our_uid = nix::unistd::Uid::effective().as_raw();
+        if uid != Some(our_uid) {
+            return Err(fdo::Error::AccessDenied(format!(
+                "Only callers running as user {our_uid} are allowed",
+            )));
+        }
+
+        Ok(())
+    }
+
     /// Close the connection.
     ///
     /// After this call, all reading and writing operations will fail.
@@ -1236,6 +1448,25 @@ impl Connection {
             .map_err(Into::into)
     }
 
+    /// The I/O statistics of this connection.
+    ///
+    /// The counters cover all messages sent and received on the connection since it was
+    /// established, including the ones exchanged internally by zbus (e.g. `AddMatch` calls). The
+    /// handshake, which includes the `Hello` call on bus connections, is not counted.
+    #[cfg(feature = "statistics")]
+    pub fn statistics(&self) -> Statistics {
+        self.inner.statistics.snapshot()
+    }
+
+    /// Get a handle to shut down this connection from anywhere, including other threads.
+    ///
+    /// This is useful for stopping a service whose task or thread is waiting for incoming messages,
+    /// without having to drop all references to the connection. See [`ShutdownHandle::shutdown`]
+    /// for details.
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        ShutdownHandle::new(self.inner.shutdown.clone())
+    }
+
     /// Gracefully close the connection, waiting for all other references to be dropped.
     ///
     /// This will not disrupt any incoming or outgoing method calls, and will await their
@@ -1290,6 +1521,7 @@ impl Connection {
         socket_read: Box<dyn socket::ReadHalf>,
         already_read: Vec<u8>,
         #[cfg(unix)] already_received_fds: Vec<std::os::fd::OwnedFd>,
+        inline_handler: Option<InlineHandler>,
     ) {
         let inner = &self.inner;
         inner
@@ -1302,6 +1534,10 @@ impl Connection {
                     #[cfg(unix)]
                     already_received_fds,
                     inner.activity_event.clone(),
+                    inner.shutdown.clone(),
+                    #[cfg(feature = "statistics")]
+                    inner.statistics.clone(),
+                    inline_handler,
                 )
                 .spawn(&inner.executor),
             )
@@ -1317,6 +1553,92 @@ impl Connection {
     }
 }
 
+// The first file descriptor passed through socket activation, as per `sd_listen_fds(3)`.
+#[cfg(unix)]
+const LISTEN_FDS_START: std::os::fd::RawFd = 3;
+
+/// Get the socket passed through socket activation, if any.
+///
+/// Only the first call takes the ownership of the socket. Later calls return `Ok(None)`.
+#[cfg(unix)]
+fn activation_socket() -> Result<Option<std::os::unix::net::UnixStream>> {
+    use nix::fcntl::{fcntl, FcntlArg, FdFlag};
+    use std::{
+        env,
+        os::fd::FromRawFd,
+        sync::atomic::{AtomicBool, Ordering},
+    };
+
+    // Whether the socket has already been taken. We can't unset the environment variables to mark
+    // that, as `sd_listen_fds(3)` does, since that's not thread-safe.
+    static TAKEN: AtomicBool = AtomicBool::new(false);
+
+    let fd = match activation_fd(
+        env::var("LISTEN_PID").ok().as_deref(),
+        env::var("LISTEN_FDS").ok().as_deref(),
+        env::var("LISTEN_FDNAMES").ok().as_deref(),
+    )? {
+        Some(fd) => fd,
+        None => return Ok(None),
+    };
+    if TAKEN.swap(true, Ordering::SeqCst) {
+        return Ok(None);
+    }
+    // Passed file descriptors are not close-on-exec.
+    fcntl(fd, FcntlArg::F_SETFD(FdFlag::FD_CLOEXEC)).map_err(io::Error::from)?;
+
+    // SAFETY: The service manager passed us the ownership of this file descriptor and `TAKEN`
+    // ensures that we only take it once.
+    Ok(Some(unsafe {
+        std::os::unix::net::UnixStream::from_raw_fd(fd)
+    }))
+}
+
+/// Find the socket to use from the values of the `LISTEN_PID`, `LISTEN_FDS` and `LISTEN_FDNAMES`
+/// environment variables.
+#[cfg(unix)]
+fn activation_fd(
+    pid: Option<&str>,
+    num_fds: Option<&str>,
+    names: Option<&str>,
+) -> Result<Option<std::os::fd::RawFd>> {
+    let pid = match pid {
+        Some(pid) => pid,
+        None => return Ok(None),
+    };
+    let pid: u32 = pid
+        .parse()
+        .map_err(|_| Error::Failure(format!("Invalid `LISTEN_PID`: {pid}")))?;
+    if pid != std::process::id() {
+        // The sockets were meant for another process.
+        return Ok(None);
+    }
+    let num_fds = match num_fds {
+        Some(num_fds) => num_fds,
+        None => return Ok(None),
+    };
+    let num_fds: std::os::fd::RawFd = num_fds
+        .parse()
+        .map_err(|_| Error::Failure(format!("Invalid `LISTEN_FDS`: {num_fds}")))?;
+    if num_fds <= 0 {
+        return Ok(None);
+    }
+
+    let index = names
+        .and_then(|names| names.split(':').position(|name| name == "dbus"))
+        .unwrap_or(0);
+    let index: std::os::fd::RawFd = index
+        .try_into()
+        .map_err(|_| Error::Failure(format!("Invalid `LISTEN_FDNAMES` index: {index}")))?;
+    if index >= num_fds {
+        return Err(Error::Failure(
+            "`LISTEN_FDNAMES` doesn't match `LISTEN_FDS`".to_string(),
+        ));
+    }
+
+    Ok(Some(LISTEN_FDS_START + index))
+}
+
 impl From<crate::blocking::Connection> for Connection {
     fn from(conn: crate::blocking::Connection) -> Self {
         conn.into_inner()
@@ -1374,6 +1696,41 @@ mod tests {
     use std::{pin::pin, time::Duration};
     use test_log::test;
 
+    #[cfg(unix)]
+    #[test]
+    fn activation_fd() {
+        let pid = std::process::id().to_string();
+        let pid = Some(pid.as_str());
+
+        // Nothing passed.
+        assert_eq!(super::activation_fd(None, None, None).unwrap(), None);
+        assert_eq!(super::activation_fd(pid, None, None).unwrap(), None);
+        assert_eq!(super::activation_fd(pid, Some("0"), None).unwrap(), None);
+
+        // Sockets meant for another process.
+        let other_pid = (std::process::id() + 1).to_string();
+        assert_eq!(
+            super::activation_fd(Some(&other_pid), Some("1"), None).unwrap(),
+            None
+        );
+
+        // The first socket is used, unless one is named `dbus`.
+        assert_eq!(super::activation_fd(pid, Some("2"), None).unwrap(), Some(3));
+        assert_eq!(
+            super::activation_fd(pid, Some("2"), Some("http:dbus")).unwrap(),
+            Some(4)
+        );
+        assert_eq!(
+            super::activation_fd(pid, Some("2"), Some("http:ftp")).unwrap(),
+            Some(3)
+        );
+
+        // Invalid values.
+        super::activation_fd(Some("not-a-pid"), Some("1"), None).unwrap_err();
+        super::activation_fd(pid, Some("many"), None).unwrap_err();
+        super::activation_fd(pid, Some("1"), Some("http:dbus")).unwrap_err();
+    }
+
     #[cfg(windows)]
     #[test]
     fn connect_autolaunch_session_bus() {
@@ -1440,6 +1797,146 @@ mod tests {
         assert!(!name_has_owner);
     }
 
+    #[cfg(unix)]
+    #[test]
+    #[timeout(15000)]
+    fn unix_fds_supported() {
+        crate::utils::block_on(async {
+            // The bus socket is a Unix socket so the daemon must agree to pass FDs.
+            let conn = Connection::session().await.unwrap();
+            assert!(conn.unix_fds_supported());
+        });
+    }
+
+    #[cfg(unix)]
+    #[test]
+    #[timeout(15000)]
+    fn peer_pid_check() {
+        struct Echo;
+
+        #[crate::interface(name = "org.zbus.PeerCheck")]
+        impl Echo {
+            fn echo(&self, s: String) -> String {
+                s
+            }
+        }
+
+        crate::utils::block_on(async {
+            let service = Builder::session()
+                .unwrap()
+                .peer_pid_check(true)
+                .serve_at("/", Echo)
+                .unwrap()
+                .build()
+                .await
+                .unwrap();
+
+            // We're both running as the same user, so the bus vouches for us.
+            let client = Connection::session().await.unwrap();
+            let reply: String = client
+                .call_method(
+                    Some(service.unique_name().unwrap()),
+                    "/",
+                    Some("org.zbus.PeerCheck"),
+                    "Echo",
+                    &"hi",
+                )
+                .await
+                .unwrap()
+                .body()
+                .deserialize()
+                .unwrap();
+            assert_eq!(reply, "hi");
+        });
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn name_taken() {
+        let name = "org.zbus.NameTakenTest";
+        let owner = blocking::Connection::session().unwrap();
+        owner.request_name(name).unwrap();
+        // Requesting it again is fine for the owner.
+        owner.request_name(name).unwrap();
+
+        let other = blocking::Connection::session().unwrap();
+        assert!(matches!(
+            other.request_name(name).unwrap_err(),
+            Error::NameTaken
+        ));
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn address() {
+        let conn = blocking::Connection::session().unwrap();
+        assert_eq!(conn.address(), Some(&crate::Address::session().unwrap()));
+    }
+
+    #[cfg(feature = "statistics")]
+    #[test]
+    #[timeout(15000)]
+    fn statistics() {
+        let conn = blocking::Connection::session().unwrap();
+        let before = conn.statistics();
+        assert_eq!(before.messages_sent(), 0);
+        assert_eq!(before.errors(), 0);
+
+        let msg = Message::signal("/org/zbus/Statistics", "org.zbus.Statistics", "Sent")
+            .unwrap()
+            .build(&("hi", 42u32))
+            .unwrap();
+        conn.send(&msg).unwrap();
+        let reply = conn
+            .call_method(
+                Some("org.freedesktop.DBus"),
+                "/org/freedesktop/DBus",
+                Some("org.freedesktop.DBus.Peer"),
+                "Ping",
+                &(),
+            )
+            .unwrap();
+
+        let after = conn.statistics();
+        assert_eq!(after.messages_sent(), before.messages_sent() + 2);
+        assert!(after.bytes_sent() > before.bytes_sent() + msg.data().len() as u64);
+        assert!(after.messages_received() > before.messages_received());
+        assert!(after.bytes_received() >= before.bytes_received() + reply.data().len() as u64);
+        assert_eq!(after.errors(), 0);
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn shutdown_handle() {
+        let conn = crate::blocking::Connection::session().unwrap();
+        let handle = conn.shutdown_handle();
+        let rule = crate::MatchRule::builder()
+            .msg_type(crate::message::Type::Signal)
+            .interface("org.zbus.ShutdownTest")
+            .unwrap()
+            .build();
+        let mut stream =
+            crate::blocking::MessageIterator::for_match_rule(rule, &conn, None).unwrap();
+
+        // Nothing is going to match the rule so this blocks until we shut down.
+        let reader = std::thread::spawn(move || stream.next());
+        std::thread::sleep(Duration::from_millis(100));
+        assert!(!handle.is_shut_down());
+        handle.shutdown();
+        assert!(handle.is_shut_down());
+
+        let res = reader.join().unwrap();
+        assert_eq!(res.unwrap().unwrap_err(), Error::Shutdown);
+
+        let msg = Message::method("/org/freedesktop/DBus", "Ping")
+            .unwrap()
+            .destination("org.freedesktop.DBus")
+            .unwrap()
+            .build(&())
+            .unwrap();
+        assert_eq!(conn.send(&msg).unwrap_err(), Error::Shutdown);
+    }
+
     #[tokio::test(start_paused = true)]
     #[timeout(15000)]
     async fn test_graceful_shutdown() {
@@ -1649,6 +2146,13 @@ mod p2p_tests {
     }
 
     async fn tcp_p2p_pipe() -> Result<(Connection, Connection)> {
+        tcp_p2p_pipe_with(|builder| builder).await
+    }
+
+    /// Same as `tcp_p2p_pipe` but `customize` is applied to the builder of the first connection.
+    async fn tcp_p2p_pipe_with(
+        customize: fn(Builder<'static>) -> Builder<'static>,
+    ) -> Result<(Connection, Connection)> {
         let guid = Guid::generate();
 
         #[cfg(not(feature = "tokio"))]
@@ -1659,11 +2163,13 @@ mod p2p_tests {
             let p0 = listener.incoming().next().unwrap().unwrap();
 
             (
-                Builder::tcp_stream(p0)
-                    .server(guid)
-                    .unwrap()
-                    .p2p()
-                    .auth_mechanism(AuthMechanism::Anonymous),
+                customize(
+                    Builder::tcp_stream(p0)
+                        .server(guid)
+                        .unwrap()
+                        .p2p()
+                        .auth_mechanism(AuthMechanism::Anonymous),
+                ),
                 Builder::tcp_stream(p1).p2p(),
             )
         };
@@ -1676,11 +2182,13 @@ mod p2p_tests {
             let p0 = listener.accept().await.unwrap().0;
 
             (
-                Builder::tcp_stream(p0)
-                    .server(guid)
-                    .unwrap()
-                    .p2p()
-                    .auth_mechanism(AuthMechanism::Anonymous),
+                customize(
+                    Builder::tcp_stream(p0)
+                        .server(guid)
+                        .unwrap()
+                        .p2p()
+                        .auth_mechanism(AuthMechanism::Anonymous),
+                ),
                 Builder::tcp_stream(p1).p2p(),
             )
         };
@@ -1688,6 +2196,56 @@ mod p2p_tests {
         futures_util::try_join!(server_conn_builder.build(), client_conn_builder.build())
     }
 
+    #[cfg(unix)]
+    #[test]
+    #[timeout(15000)]
+    fn peer_pid_check() {
+        crate::utils::block_on(test_peer_pid_check()).unwrap();
+    }
+
+    #[cfg(unix)]
+    async fn test_peer_pid_check() -> Result<()> {
+        struct Echo;
+
+        #[crate::interface(name = "org.zbus.PeerCheck")]
+        impl Echo {
+            fn echo(&self, s: String) -> String {
+                s
+            }
+        }
+
+        async fn call(client: &Connection) -> Result<String> {
+            client
+                .call_method(None::<()>, "/", Some("org.zbus.PeerCheck"), "Echo", &"hi")
+                .await?
+                .body()
+                .deserialize()
+        }
+
+        // Serve through the builder, so the object server is ready before the first call.
+        fn serve_checked(builder: Builder<'static>) -> Builder<'static> {
+            builder.peer_pid_check(true).serve_at("/", Echo).unwrap()
+        }
+
+        // The peer on the other end of a Unix socket is running as the same user as us.
+        let (_server, client) = unix_p2p_pipe_with(serve_checked).await?;
+        assert_eq!(call(&client).await?, "hi");
+
+        // The user of a peer over TCP is unknown.
+        let (_server, client) =
+            tcp_p2p_pipe_with(|builder| builder.serve_at("/", Echo).unwrap()).await?;
+        assert_eq!(call(&client).await?, "hi");
+        let (_server, client) = tcp_p2p_pipe_with(serve_checked).await?;
+        match call(&client).await.unwrap_err() {
+            Error::MethodError(name, _, _) => {
+                assert_eq!(name, "org.freedesktop.DBus.Error.AccessDenied")
+            }
+            e => panic!("unexpected error: {e}"),
+        }
+
+        Ok(())
+    }
+
     #[cfg(unix)]
     #[test]
     #[timeout(15000)]
@@ -1705,6 +2263,14 @@ mod p2p_tests {
 
     #[cfg(unix)]
     async fn unix_p2p_pipe() -> Result<(Connection, Connection)> {
+        unix_p2p_pipe_with(|builder| builder).await
+    }
+
+    /// Same as `unix_p2p_pipe` but `customize` is applied to the builder of the first connection.
+    #[cfg(unix)]
+    async fn unix_p2p_pipe_with(
+        customize: fn(Builder<'static>) -> Builder<'static>,
+    ) -> Result<(Connection, Connection)> {
         #[cfg(not(feature = "tokio"))]
         use std::os::unix::net::UnixStream;
         #[cfg(feature = "tokio")]
@@ -1717,7 +2283,7 @@ mod p2p_tests {
         let (p0, p1) = UnixStream::pair().unwrap();
 
         futures_util::try_join!(
-            Builder::unix_stream(p1).p2p().build(),
+            customize(Builder::unix_stream(p1).p2p()).build(),
             Builder::unix_stream(p0).server(guid).unwrap().p2p().build(),
         )
     }
@@ -1908,6 +2474,139 @@ mod p2p_tests {
         .map(|_| ())
     }
 
+    #[test]
+    #[timeout(15000)]
+    fn inline_handler() {
+        crate::utils::block_on(test_inline_handler()).unwrap();
+    }
+
+    async fn test_inline_handler() -> Result<()> {
+        let (a, b) = socket::Channel::pair();
+        let guid = crate::Guid::generate();
+        let seen = Arc::new(std::sync::Mutex::new(vec![]));
+        let client = {
+            let seen = seen.clone();
+            Builder::authenticated_socket(a, guid.clone())?
+                .p2p()
+                .inline_handler(move |msg| seen.lock().unwrap().push(msg.message_type()))
+                .build()
+                .await?
+        };
+        let server = Builder::authenticated_socket(b, guid)?
+            .p2p()
+            .build()
+            .await?;
+
+        let mut stream = MessageStream::from(&server);
+        let server_future = async move {
+            let call = stream.try_next().await?.unwrap();
+            // The signal goes out on the wire before the reply.
+            server
+                .emit_signal(None::<()>, "/", "org.zbus.p2p", "ASignalForYou", &())
+                .await?;
+            server.reply(&call, &()).await
+        };
+        let client_future = async {
+            client
+                .call_method(None::<()>, "/", Some("org.zbus.p2p"), "Test", &())
+                .await?;
+            // So the handler must have seen it by the time the call returns.
+            assert_eq!(*seen.lock().unwrap(), [Type::Signal, Type::MethodReturn]);
+
+            Ok(())
+        };
+
+        futures_util::try_join!(server_future, client_future).map(|_| ())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn method_timeout() {
+        crate::utils::block_on(test_method_timeout()).unwrap();
+    }
+
+    async fn test_method_timeout() -> Result<()> {
+        let (a, b) = socket::Channel::pair();
+        let guid = crate::Guid::generate();
+        let client = Builder::authenticated_socket(a, guid.clone())?
+            .p2p()
+            .method_timeout(Duration::from_millis(50))
+            .build()
+            .await?;
+        assert_eq!(client.method_timeout(), Some(Duration::from_millis(50)));
+        let server = Builder::authenticated_socket(b, guid)?
+            .p2p()
+            .build()
+            .await?;
+
+        let mut stream = MessageStream::from(&server);
+        let server_future = async move {
+            // Only reply to the first call once the second one arrives, i-e after it timed out.
+            let first = stream.try_next().await?.unwrap();
+            let second = stream.try_next().await?.unwrap();
+            server.reply(&first, &"late").await?;
+            server.reply(&second, &"on time").await?;
+
+            // Same for the third and fourth ones, but take longer than the connection's timeout to
+            // reply to the fourth one.
+            let third = stream.try_next().await?.unwrap();
+            let fourth = stream.try_next().await?.unwrap();
+            server.reply(&third, &"late").await?;
+            timeout(Duration::from_millis(200), std::future::pending::<()>()).await;
+            server.reply(&fourth, &"patient").await
+        };
+        let client_future = async {
+            let err = client
+                .call_method(None::<()>, "/", Some("org.zbus.p2p"), "Test", &())
+                .await
+                .unwrap_err();
+            assert!(matches!(err, Error::InputOutput(e) if e.kind() == ErrorKind::TimedOut));
+
+            // A proxy can be more patient than its connection, and the late reply to the first
+            // call must not be mistaken for the reply to this one.
+            let proxy: crate::Proxy<'_> = crate::proxy::Builder::new(&client)
+                .destination("org.zbus.p2p")?
+                .path("/")?
+                .interface("org.zbus.p2p")?
+                .cache_properties(CacheProperties::No)
+                .method_timeout(Duration::from_secs(10))
+                .build()
+                .await?;
+            assert_eq!(proxy.method_timeout(), Some(Duration::from_secs(10)));
+            let reply: String = proxy.call("Test", &()).await?;
+            assert_eq!(reply, "on time");
+
+            // A single call can also override the connection timeout, either way.
+            let err = client
+                .call_method_with_timeout(
+                    None::<()>,
+                    "/",
+                    Some("org.zbus.p2p"),
+                    "Test",
+                    &(),
+                    Duration::from_millis(20),
+                )
+                .await
+                .unwrap_err();
+            assert!(matches!(err, Error::InputOutput(e) if e.kind() == ErrorKind::TimedOut));
+            let reply = client
+                .call_method_with_timeout(
+                    None::<()>,
+                    "/",
+                    Some("org.zbus.p2p"),
+                    "Test",
+                    &(),
+                    Duration::from_secs(10),
+                )
+                .await?;
+            assert_eq!(reply.body().deserialize::<String>()?, "patient");
+
+            Ok(())
+        };
+
+        futures_util::try_join!(server_future, client_future).map(|_| ())
+    }
+
     #[test]
     #[timeout(15000)]
     fn channel_pair() {
@@ -1917,6 +2616,9 @@ mod p2p_tests {
     async fn test_channel_pair() -> Result<()> {
         let (server1, client1) = create_channel_pair().await;
         let (server2, client2) = create_channel_pair().await;
+        // Not connected through an address.
+        assert!(server1.address().is_none());
+        assert!(client1.address().is_none());
 
         test_p2p(server1, client1, server2, client2).await
     }
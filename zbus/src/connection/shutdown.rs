@@ -0,0 +1,80 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use event_listener::Event;
+use static_assertions::assert_impl_all;
+
+/// A handle to shut down a [`Connection`] from anywhere.
+///
+/// This is created by [`Connection::shutdown_handle`]. It's cheap to clone and can be freely sent
+/// to other threads, which makes it suitable to stop a service whose thread is blocked waiting for
+/// incoming messages.
+///
+/// [`Connection`]: crate::Connection
+/// [`Connection::shutdown_handle`]: crate::Connection::shutdown_handle
+#[derive(Debug, Clone)]
+pub struct ShutdownHandle {
+    state: Arc<ShutdownState>,
+}
+
+assert_impl_all!(ShutdownHandle: Send, Sync, Unpin);
+
+impl ShutdownHandle {
+    pub(crate) fn new(state: Arc<ShutdownState>) -> Self {
+        Self { state }
+    }
+
+    /// Shut down the connection.
+    ///
+    /// The connection stops reading from the socket and any pending or future receive on it
+    /// (message streams and iterators, as well as method calls awaiting a reply) fails with
+    /// [`Error::Shutdown`]. Any subsequent attempt to send a message on the connection also fails
+    /// with the same error.
+    ///
+    /// This method doesn't block and it's safe to call it multiple times.
+    ///
+    /// [`Error::Shutdown`]: crate::Error::Shutdown
+    pub fn shutdown(&self) {
+        self.state.shutdown();
+    }
+
+    /// Whether the connection has been shut down.
+    pub fn is_shut_down(&self) -> bool {
+        self.state.is_shut_down()
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct ShutdownState {
+    shut_down: AtomicBool,
+    event: Event,
+}
+
+impl ShutdownState {
+    pub(crate) fn shutdown(&self) {
+        if !self.shut_down.swap(true, Ordering::SeqCst) {
+            self.event.notify(usize::MAX);
+        }
+    }
+
+    pub(crate) fn is_shut_down(&self) -> bool {
+        self.shut_down.load(Ordering::SeqCst)
+    }
+
+    /// Wait until the connection is shut down.
+    pub(crate) async fn wait(&self) {
+        loop {
+            if self.is_shut_down() {
+                return;
+            }
+            let listener = self.event.listen();
+            // Check again in case we were shut down before we started listening.
+            if self.is_shut_down() {
+                return;
+            }
+            listener.await;
+        }
+    }
+}
@@ -20,10 +20,10 @@ use tracing::trace;
 use crate::{
     fdo::ConnectionCredentials,
     message::{
-        header::{MAX_MESSAGE_SIZE, MIN_MESSAGE_SIZE},
+        header::{message_layout, MIN_MESSAGE_SIZE},
         PrimaryHeader,
     },
-    padding_for_8_bytes, Message,
+    Message,
 };
 #[cfg(unix)]
 use std::os::fd::{AsFd, BorrowedFd, OwnedFd};
@@ -134,13 +134,7 @@ pub trait ReadHalf: std::fmt::Debug + Send + Sync + 'static {
         };
 
         let (primary_header, fields_len) = PrimaryHeader::read(&bytes)?;
-        let header_len = MIN_MESSAGE_SIZE + fields_len as usize;
-        let body_padding = padding_for_8_bytes(header_len);
-        let body_len = primary_header.body_len() as usize;
-        let total_len = header_len + body_padding + body_len;
-        if total_len > MAX_MESSAGE_SIZE {
-            return Err(crate::Error::ExcessData);
-        }
+        let (_, total_len) = message_layout(fields_len, primary_header.body_len())?;
 
         // By this point we have a full primary header, so we know the exact length of the complete
         // message.
@@ -186,6 +180,8 @@ pub trait ReadHalf: std::fmt::Debug + Send + Sync + 'static {
         if !already_received_fds.is_empty() {
             use crate::message::header::PRIMARY_HEADER_SIZE;
 
+            // Can't overflow since `message_layout` already checked the total length.
+            let header_len = MIN_MESSAGE_SIZE + fields_len as usize;
             let ctxt = Context::new_dbus(endian, PRIMARY_HEADER_SIZE);
             let encoded_fields =
                 serialized::Data::new(&bytes[PRIMARY_HEADER_SIZE..header_len], ctxt);
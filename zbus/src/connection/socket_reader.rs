@@ -1,13 +1,25 @@
 use std::{collections::HashMap, sync::Arc};
 
 use event_listener::Event;
+use futures_util::future::{select, Either};
 use tracing::{debug, instrument, trace};
 
 use crate::{
-    async_lock::Mutex, connection::MsgBroadcaster, Executor, Message, OwnedMatchRule, Task,
+    async_lock::Mutex, connection::MsgBroadcaster, Error, Executor, Message, OwnedMatchRule, Task,
 };
 
-use super::socket::ReadHalf;
+#[cfg(feature = "statistics")]
+use super::statistics::StatisticsCounters;
+use super::{shutdown::ShutdownState, socket::ReadHalf};
+
+/// A callback invoked by the socket reader for each incoming message, before dispatching it.
+pub(crate) struct InlineHandler(pub(crate) Box<dyn FnMut(&Message) + Send + Sync>);
+
+impl std::fmt::Debug for InlineHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InlineHandler").finish_non_exhaustive()
+    }
+}
 
 #[derive(Debug)]
 pub(crate) struct SocketReader {
@@ -18,15 +30,23 @@ pub(crate) struct SocketReader {
     already_received_fds: Vec<std::os::fd::OwnedFd>,
     prev_seq: u64,
     activity_event: Arc<Event>,
+    shutdown: Arc<ShutdownState>,
+    #[cfg(feature = "statistics")]
+    statistics: Arc<StatisticsCounters>,
+    inline_handler: Option<InlineHandler>,
 }
 
 impl SocketReader {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         socket: Box<dyn ReadHalf>,
         senders: Arc<Mutex<HashMap<Option<OwnedMatchRule>, MsgBroadcaster>>>,
         already_received_bytes: Vec<u8>,
         #[cfg(unix)] already_received_fds: Vec<std::os::fd::OwnedFd>,
         activity_event: Arc<Event>,
+        shutdown: Arc<ShutdownState>,
+        #[cfg(feature = "statistics")] statistics: Arc<StatisticsCounters>,
+        inline_handler: Option<InlineHandler>,
     ) -> Self {
         Self {
             socket,
@@ -36,6 +56,10 @@ impl SocketReader {
             already_received_fds,
             prev_seq: 0,
             activity_event,
+            shutdown,
+            #[cfg(feature = "statistics")]
+            statistics,
+            inline_handler,
         }
     }
 
@@ -46,11 +70,26 @@ impl SocketReader {
     // Keep receiving messages and put them on the queue.
     #[instrument(name = "socket reader", skip(self))]
     async fn receive_msg(mut self) {
+        let shutdown = self.shutdown.clone();
         loop {
             trace!("Waiting for message on the socket..");
-            let msg = self.read_socket().await;
+            let msg = {
+                let read = std::pin::pin!(self.read_socket());
+                let shutdown = std::pin::pin!(shutdown.wait());
+                match select(read, shutdown).await {
+                    Either::Left((msg, _)) => msg,
+                    Either::Right(_) => Err(Error::Shutdown),
+                }
+            };
+            #[cfg(feature = "statistics")]
+            self.statistics.received(&msg);
             match &msg {
-                Ok(msg) => trace!("Message received on the socket: {:?}", msg),
+                Ok(msg) => {
+                    trace!("Message received on the socket: {:?}", msg);
+                    if let Some(handler) = &mut self.inline_handler {
+                        (handler.0)(msg);
+                    }
+                }
                 Err(e) => trace!("Error reading from the socket: {:?}", e),
             };
 
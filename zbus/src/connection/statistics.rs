@@ -0,0 +1,95 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use static_assertions::assert_impl_all;
+
+/// I/O statistics of a [`Connection`].
+///
+/// This is a snapshot of the counters at the time it was taken, as returned by
+/// [`Connection::statistics`].
+///
+/// [`Connection`]: crate::Connection
+/// [`Connection::statistics`]: crate::Connection::statistics
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Statistics {
+    messages_sent: u64,
+    messages_received: u64,
+    bytes_sent: u64,
+    bytes_received: u64,
+    errors: u64,
+}
+
+assert_impl_all!(Statistics: Send, Sync, Unpin);
+
+impl Statistics {
+    /// The number of messages successfully sent on the connection.
+    pub fn messages_sent(&self) -> u64 {
+        self.messages_sent
+    }
+
+    /// The number of messages received on the connection.
+    pub fn messages_received(&self) -> u64 {
+        self.messages_received
+    }
+
+    /// The number of bytes of all messages successfully sent on the connection.
+    pub fn bytes_sent(&self) -> u64 {
+        self.bytes_sent
+    }
+
+    /// The number of bytes of all messages received on the connection.
+    pub fn bytes_received(&self) -> u64 {
+        self.bytes_received
+    }
+
+    /// The number of failed attempts to send or receive a message.
+    pub fn errors(&self) -> u64 {
+        self.errors
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct StatisticsCounters {
+    messages_sent: AtomicU64,
+    messages_received: AtomicU64,
+    bytes_sent: AtomicU64,
+    bytes_received: AtomicU64,
+    errors: AtomicU64,
+}
+
+impl StatisticsCounters {
+    pub(crate) fn sent<T>(&self, res: &crate::Result<T>, len: usize) {
+        match res {
+            Ok(_) => {
+                self.messages_sent.fetch_add(1, Ordering::Relaxed);
+                self.bytes_sent.fetch_add(len as u64, Ordering::Relaxed);
+            }
+            Err(_) => {
+                self.errors.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    pub(crate) fn received(&self, res: &crate::Result<crate::Message>) {
+        match res {
+            Ok(msg) => {
+                self.messages_received.fetch_add(1, Ordering::Relaxed);
+                self.bytes_received
+                    .fetch_add(msg.data().len() as u64, Ordering::Relaxed);
+            }
+            Err(crate::Error::Shutdown) => (),
+            Err(_) => {
+                self.errors.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    pub(crate) fn snapshot(&self) -> Statistics {
+        Statistics {
+            messages_sent: self.messages_sent.load(Ordering::Relaxed),
+            messages_received: self.messages_received.load(Ordering::Relaxed),
+            bytes_sent: self.bytes_sent.load(Ordering::Relaxed),
+            bytes_received: self.bytes_received.load(Ordering::Relaxed),
+            errors: self.errors.load(Ordering::Relaxed),
+        }
+    }
+}
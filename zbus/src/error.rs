@@ -61,6 +61,10 @@ pub enum Error {
     InvalidSerial,
     /// The given interface already exists at the given path.
     InterfaceExists(InterfaceName<'static>, ObjectPath<'static>),
+    /// The connection was shut down through a [`ShutdownHandle`].
+    ///
+    /// [`ShutdownHandle`]: crate::connection::ShutdownHandle
+    Shutdown,
 }
 
 assert_impl_all!(Error: Send, Sync, Unpin);
@@ -78,6 +82,7 @@ impl PartialEq for Error {
             (Self::MissingField, Self::MissingField) => true,
             (Self::InvalidGUID, Self::InvalidGUID) => true,
             (Self::InvalidSerial, Self::InvalidSerial) => true,
+            (Self::Shutdown, Self::Shutdown) => true,
             (Self::Unsupported, Self::Unsupported) => true,
             (Self::FDO(s), Self::FDO(o)) => s == o,
             (Self::InvalidField, Self::InvalidField) => true,
@@ -117,6 +122,7 @@ impl error::Error for Error {
             Error::MissingParameter(_) => None,
             Error::InvalidSerial => None,
             Error::InterfaceExists(_, _) => None,
+            Error::Shutdown => None,
         }
     }
 }
@@ -152,6 +158,7 @@ impl fmt::Display for Error {
             }
             Error::InvalidSerial => write!(f, "Serial number in the message header is 0"),
             Error::InterfaceExists(i, p) => write!(f, "Interface `{i}` already exists at `{p}`"),
+            Error::Shutdown => write!(f, "The connection was shut down"),
         }
     }
 }
@@ -182,6 +189,7 @@ impl Clone for Error {
             Error::MissingParameter(p) => Error::MissingParameter(p),
             Error::InvalidSerial => Error::InvalidSerial,
             Error::InterfaceExists(i, p) => Error::InterfaceExists(i.clone(), p.clone()),
+            Error::Shutdown => Error::Shutdown,
         }
     }
 }
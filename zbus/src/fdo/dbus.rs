@@ -4,17 +4,27 @@
 //! be useful across various D-Bus applications. This module provides their proxy.
 
 use enumflags2::{bitflags, BitFlags};
+use futures_core::{ready, Stream};
+use futures_util::future::join_all;
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use static_assertions::assert_impl_all;
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    pin::Pin,
+    str::FromStr,
+    task::{Context, Poll},
+};
+use tracing::debug;
 use zbus_names::{
-    BusName, OwnedBusName, OwnedInterfaceName, OwnedUniqueName, UniqueName, WellKnownName,
+    BusName, OwnedBusName, OwnedInterfaceName, OwnedUniqueName, OwnedWellKnownName, UniqueName,
+    WellKnownName,
 };
 use zvariant::{DeserializeDict, Optional, SerializeDict, Type};
 
 use super::Result;
-use crate::{proxy, OwnedGuid};
+use crate::{proxy, Connection, OwnedGuid};
 
 /// The flags used by the bus [`request_name`] method.
 ///
@@ -248,6 +258,219 @@ impl ConnectionCredentials {
     }
 }
 
+/// A feature of the message bus, as listed in the [`DBusProxy::features`] property.
+///
+/// Features not known to zbus are preserved as [`Features::Other`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Features {
+    /// The bus enforces AppArmor mediation policies.
+    AppArmor,
+    /// The bus filters out header fields it does not recognize and only forwards those it trusts.
+    HeaderFiltering,
+    /// The bus enforces SELinux mediation policies.
+    SELinux,
+    /// The bus supports activating services through systemd.
+    SystemdActivation,
+    /// A feature unknown to zbus.
+    Other(String),
+}
+
+assert_impl_all!(Features: Send, Sync, Unpin);
+
+impl Features {
+    /// The name of the feature, as used by the bus.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Features::AppArmor => "AppArmor",
+            Features::HeaderFiltering => "HeaderFiltering",
+            Features::SELinux => "SELinux",
+            Features::SystemdActivation => "SystemdActivation",
+            Features::Other(s) => s,
+        }
+    }
+}
+
+impl From<&str> for Features {
+    fn from(s: &str) -> Self {
+        match s {
+            "AppArmor" => Features::AppArmor,
+            "HeaderFiltering" => Features::HeaderFiltering,
+            "SELinux" => Features::SELinux,
+            "SystemdActivation" => Features::SystemdActivation,
+            _ => Features::Other(s.to_string()),
+        }
+    }
+}
+
+impl From<String> for Features {
+    fn from(s: String) -> Self {
+        match Features::from(s.as_str()) {
+            Features::Other(_) => Features::Other(s),
+            f => f,
+        }
+    }
+}
+
+impl FromStr for Features {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(s.into())
+    }
+}
+
+impl fmt::Display for Features {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Get the features supported by the message bus `conn` is connected to.
+///
+/// This is a typed wrapper around the `Features` property of the `org.freedesktop.DBus` interface.
+pub async fn get_supported_features(conn: &Connection) -> Result<Vec<Features>> {
+    let proxy = DBusProxy::new(conn).await?;
+
+    proxy
+        .features()
+        .await
+        .map(|features| features.into_iter().map(Features::from).collect())
+}
+
+/// Get the owners of all the well-known names on the message bus `conn` is connected to.
+///
+/// The names are listed with `ListNames` and then the owner of each of them is queried with
+/// `GetNameOwner`. While these calls are issued concurrently, this still means a round-trip to the
+/// bus per name, so avoid calling this often. To keep track of the owners over time, use
+/// [`watch_name_owners`] or a `NameOwnerChanged` stream instead.
+///
+/// Names that lose their owner between the two steps are left out.
+pub async fn get_name_owners(
+    conn: &Connection,
+) -> Result<HashMap<OwnedWellKnownName, OwnedUniqueName>> {
+    let proxy = DBusProxy::new(conn).await?;
+    let names = proxy
+        .list_names()
+        .await?
+        .into_iter()
+        .filter_map(|name| match name.into_inner() {
+            BusName::WellKnown(name) => Some(OwnedWellKnownName::from(name)),
+            BusName::Unique(_) => None,
+        });
+    let owners = join_all(names.map(|name| {
+        let proxy = &proxy;
+        async move {
+            let owner = proxy.get_name_owner((&name).into()).await;
+            (name, owner)
+        }
+    }))
+    .await;
+
+    owners
+        .into_iter()
+        .filter_map(|(name, owner)| match owner {
+            Ok(owner) => Some(Ok((name, owner))),
+            Err(super::Error::NameHasNoOwner(_)) => None,
+            Err(e) => Some(Err(e)),
+        })
+        .collect()
+}
+
+/// Watch the ownership of any of `names`.
+///
+/// The returned stream yields the `NameOwnerChanged` signals about any of the given names. A
+/// single match rule is used for all of them and the signals about other names are filtered out
+/// on the client side, which is cheaper than using a separate stream per name (and hence a match
+/// rule each) when watching many names.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use futures_util::StreamExt;
+/// # zbus::block_on(async {
+/// let conn = zbus::Connection::session().await?;
+/// let mut stream =
+///     zbus::fdo::watch_name_owners(&conn, ["org.freedesktop.Notifications", "org.a11y.Bus"])
+///         .await?;
+/// while let Some(signal) = stream.next().await {
+///     let args = signal.args()?;
+///     println!("{}: {:?} -> {:?}", args.name(), args.old_owner(), args.new_owner());
+/// }
+/// # Ok::<(), zbus::Error>(())
+/// # }).unwrap();
+/// ```
+pub async fn watch_name_owners<'n, I, N>(
+    conn: &Connection,
+    names: I,
+) -> Result<NameOwnerWatchStream>
+where
+    I: IntoIterator<Item = N>,
+    N: TryInto<BusName<'n>>,
+    N::Error: Into<crate::Error>,
+{
+    let names = names
+        .into_iter()
+        .map(|name| {
+            name.try_into()
+                .map(OwnedBusName::from)
+                .map_err(|e| e.into().into())
+        })
+        .collect::<Result<_>>()?;
+    let stream = DBusProxy::new(conn)
+        .await?
+        .receive_name_owner_changed()
+        .await?;
+
+    Ok(NameOwnerWatchStream { stream, names })
+}
+
+/// A stream of `NameOwnerChanged` signals about a set of names, as returned by
+/// [`watch_name_owners`].
+pub struct NameOwnerWatchStream {
+    stream: NameOwnerChangedStream<'static>,
+    names: HashSet<OwnedBusName>,
+}
+
+assert_impl_all!(NameOwnerWatchStream: Send, Sync, Unpin);
+
+impl NameOwnerWatchStream {
+    /// The names being watched.
+    pub fn names(&self) -> &HashSet<OwnedBusName> {
+        &self.names
+    }
+}
+
+impl fmt::Debug for NameOwnerWatchStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NameOwnerWatchStream")
+            .field("names", &self.names)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Stream for NameOwnerWatchStream {
+    type Item = NameOwnerChanged;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            let Some(signal) = ready!(Pin::new(&mut self.stream).poll_next(cx)) else {
+                return Poll::Ready(None);
+            };
+            match signal.args() {
+                Ok(args) if self.names.contains(args.name().as_str()) => (),
+                Ok(_) => continue,
+                Err(e) => {
+                    debug!("Failed to parse `NameOwnerChanged` signal: {e}");
+
+                    continue;
+                }
+            }
+
+            return Poll::Ready(Some(signal));
+        }
+    }
+}
+
 /// Proxy for the `org.freedesktop.DBus` interface.
 #[proxy(
     default_service = "org.freedesktop.DBus",
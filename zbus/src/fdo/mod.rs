@@ -3,9 +3,10 @@ pub use error::{Error, Result};
 
 pub(crate) mod dbus;
 pub use dbus::{
-    ConnectionCredentials, DBusProxy, NameAcquired, NameAcquiredArgs, NameAcquiredStream, NameLost,
-    NameLostArgs, NameLostStream, NameOwnerChanged, NameOwnerChangedArgs, NameOwnerChangedStream,
-    ReleaseNameReply, RequestNameFlags, RequestNameReply,
+    get_name_owners, get_supported_features, watch_name_owners, ConnectionCredentials, DBusProxy,
+    Features, NameAcquired, NameAcquiredArgs, NameAcquiredStream, NameLost, NameLostArgs,
+    NameLostStream, NameOwnerChanged, NameOwnerChangedArgs, NameOwnerChangedStream,
+    NameOwnerWatchStream, ReleaseNameReply, RequestNameFlags, RequestNameReply,
 };
 
 pub(crate) mod introspectable;
@@ -32,7 +33,7 @@ pub use properties::{
 };
 
 pub(crate) mod stats;
-pub use stats::StatsProxy;
+pub use stats::{get_stats, DBusStatistics, StatsProxy};
 
 #[cfg(test)]
 mod tests {
@@ -132,6 +133,120 @@ mod tests {
             });
     }
 
+    #[test]
+    fn features_from_str() {
+        assert_eq!(fdo::Features::from("AppArmor"), fdo::Features::AppArmor);
+        assert_eq!(fdo::Features::from("SELinux"), fdo::Features::SELinux);
+        assert_eq!(
+            fdo::Features::from("SystemdActivation"),
+            fdo::Features::SystemdActivation
+        );
+        let other = fdo::Features::from("org.example.Teleportation");
+        assert_eq!(
+            other,
+            fdo::Features::Other("org.example.Teleportation".into())
+        );
+        assert_eq!(other.to_string(), "org.example.Teleportation");
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn supported_features() {
+        let conn = crate::blocking::Connection::session().unwrap();
+        let features = crate::blocking::fdo::get_supported_features(&conn).unwrap();
+        let names = crate::blocking::fdo::DBusProxy::new(&conn)
+            .unwrap()
+            .features()
+            .unwrap();
+        assert_eq!(
+            features,
+            names
+                .iter()
+                .map(|n| fdo::Features::from(n.as_str()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn stats() {
+        let conn = crate::blocking::Connection::session().unwrap();
+        let stats = match crate::blocking::fdo::get_stats(&conn) {
+            Ok(stats) => stats,
+            // Not all bus implementations provide the stats interface.
+            Err(fdo::Error::UnknownInterface(_) | fdo::Error::UnknownMethod(_)) => return,
+            Err(e) => panic!("{e}"),
+        };
+        assert!(stats.active_connections().unwrap() >= 1);
+        assert!(stats.bus_names().unwrap() >= 1);
+        assert!(stats.peak_bus_names().unwrap() >= stats.bus_names().unwrap());
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn watch_name_owners() {
+        let conn = crate::blocking::Connection::session().unwrap();
+        let watched = ["org.zbus.WatchNameOwners1", "org.zbus.WatchNameOwners2"];
+        let mut signals = crate::blocking::fdo::watch_name_owners(&conn, watched).unwrap();
+        assert_eq!(signals.names().len(), 2);
+
+        let owner = crate::blocking::Connection::session().unwrap();
+        let unique_name = owner.unique_name().unwrap().to_string();
+        for name in ["org.zbus.WatchNameOwnersUnwatched", watched[1], watched[0]] {
+            owner.request_name(name).unwrap();
+        }
+        owner.release_name(watched[1]).unwrap();
+
+        let mut next = || {
+            let signal = signals.next().unwrap();
+            let args = signal.args().unwrap();
+            (
+                args.name().to_string(),
+                args.new_owner().as_ref().map(|o| o.to_string()),
+            )
+        };
+        assert_eq!(next(), (watched[1].to_string(), Some(unique_name.clone())));
+        assert_eq!(next(), (watched[0].to_string(), Some(unique_name)));
+        assert_eq!(next(), (watched[1].to_string(), None));
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn get_name_owners() {
+        let conn = crate::blocking::Connection::session().unwrap();
+        let name = "org.zbus.GetNameOwners";
+        conn.request_name(name).unwrap();
+
+        let owners = crate::blocking::fdo::get_name_owners(&conn).unwrap();
+        let owner = &owners[name];
+        assert_eq!(owner, conn.unique_name().unwrap());
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn list_queued_owners() {
+        use enumflags2::BitFlags;
+
+        let name = WellKnownName::from_static_str("org.zbus.ListQueuedOwners").unwrap();
+        let owner = crate::blocking::Connection::session().unwrap();
+        owner.request_name(&name).unwrap();
+        let queued = crate::blocking::Connection::session().unwrap();
+        let reply = queued
+            .request_name_with_flags(&name, BitFlags::empty())
+            .unwrap();
+        assert_eq!(reply, fdo::RequestNameReply::InQueue);
+
+        let proxy = crate::blocking::fdo::DBusProxy::new(&owner).unwrap();
+        let owners = proxy.list_queued_owners(name.clone()).unwrap();
+        assert_eq!(
+            owners,
+            [
+                owner.unique_name().unwrap().clone(),
+                queued.unique_name().unwrap().clone()
+            ]
+        );
+    }
+
     #[test]
     #[timeout(15000)]
     fn no_object_manager_signals_before_hello() {
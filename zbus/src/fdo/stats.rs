@@ -6,10 +6,137 @@
 use static_assertions::assert_impl_all;
 use std::collections::HashMap;
 use zbus_names::BusName;
-use zvariant::OwnedValue;
+use zvariant::{DeserializeDict, OwnedValue, SerializeDict, Type};
 
 use super::Result;
-use crate::proxy;
+use crate::{proxy, Connection};
+
+/// Statistics of a message bus, as returned by [`get_stats`].
+///
+/// Only the keys known to be returned by dbus-daemon are provided here. Each of them is `None` if
+/// the bus didn't include it in the reply.
+#[derive(Debug, Default, DeserializeDict, PartialEq, Eq, SerializeDict, Type)]
+#[zvariant(signature = "a{sv}")]
+pub struct DBusStatistics {
+    #[zvariant(rename = "Serial")]
+    pub(crate) serial: Option<u32>,
+
+    #[zvariant(rename = "ActiveConnections")]
+    pub(crate) active_connections: Option<u32>,
+
+    #[zvariant(rename = "IncompleteConnections")]
+    pub(crate) incomplete_connections: Option<u32>,
+
+    #[zvariant(rename = "MatchRules")]
+    pub(crate) match_rules: Option<u32>,
+
+    #[zvariant(rename = "PeakMatchRules")]
+    pub(crate) peak_match_rules: Option<u32>,
+
+    #[zvariant(rename = "PeakMatchRulesPerConnection")]
+    pub(crate) peak_match_rules_per_connection: Option<u32>,
+
+    #[zvariant(rename = "BusNames")]
+    pub(crate) bus_names: Option<u32>,
+
+    #[zvariant(rename = "PeakBusNames")]
+    pub(crate) peak_bus_names: Option<u32>,
+
+    #[zvariant(rename = "PeakBusNamesPerConnection")]
+    pub(crate) peak_bus_names_per_connection: Option<u32>,
+
+    #[zvariant(rename = "ListMemPoolUsedBytes")]
+    pub(crate) list_mem_pool_used_bytes: Option<u32>,
+
+    #[zvariant(rename = "ListMemPoolCachedBytes")]
+    pub(crate) list_mem_pool_cached_bytes: Option<u32>,
+
+    #[zvariant(rename = "ListMemPoolAllocatedBytes")]
+    pub(crate) list_mem_pool_allocated_bytes: Option<u32>,
+}
+
+assert_impl_all!(DBusStatistics: Send, Sync, Unpin);
+
+impl DBusStatistics {
+    /// The serial number of the reply, useful to order snapshots of the statistics.
+    pub fn serial(&self) -> Option<u32> {
+        self.serial
+    }
+
+    /// The number of connections that completed authentication.
+    pub fn active_connections(&self) -> Option<u32> {
+        self.active_connections
+    }
+
+    /// The number of connections that are still authenticating.
+    pub fn incomplete_connections(&self) -> Option<u32> {
+        self.incomplete_connections
+    }
+
+    /// The number of match rules currently registered on the bus.
+    pub fn match_rules(&self) -> Option<u32> {
+        self.match_rules
+    }
+
+    /// The highest number of match rules registered at any time.
+    pub fn peak_match_rules(&self) -> Option<u32> {
+        self.peak_match_rules
+    }
+
+    /// The highest number of match rules registered by a single connection at any time.
+    pub fn peak_match_rules_per_connection(&self) -> Option<u32> {
+        self.peak_match_rules_per_connection
+    }
+
+    /// The number of names currently owned on the bus, including unique names.
+    pub fn bus_names(&self) -> Option<u32> {
+        self.bus_names
+    }
+
+    /// The highest number of names owned at any time.
+    pub fn peak_bus_names(&self) -> Option<u32> {
+        self.peak_bus_names
+    }
+
+    /// The highest number of names owned by a single connection at any time.
+    pub fn peak_bus_names_per_connection(&self) -> Option<u32> {
+        self.peak_bus_names_per_connection
+    }
+
+    /// The number of bytes in use by the bus's internal list memory pool.
+    pub fn list_mem_pool_used_bytes(&self) -> Option<u32> {
+        self.list_mem_pool_used_bytes
+    }
+
+    /// The number of bytes cached by the bus's internal list memory pool for reuse.
+    pub fn list_mem_pool_cached_bytes(&self) -> Option<u32> {
+        self.list_mem_pool_cached_bytes
+    }
+
+    /// The number of bytes allocated by the bus's internal list memory pool.
+    pub fn list_mem_pool_allocated_bytes(&self) -> Option<u32> {
+        self.list_mem_pool_allocated_bytes
+    }
+}
+
+/// Get the statistics of the message bus `conn` is connected to.
+///
+/// This is a typed wrapper around the `GetStats` method of the `org.freedesktop.DBus.Debug.Stats`
+/// interface. This interface is not part of the D-Bus specification and is not available
+/// everywhere:
+///
+/// * dbus-daemon provides it since version 1.6.0 if built with `--enable-stats`, and by default
+///   since version 1.9.0. On the system bus, it's only accessible to root by default.
+/// * Since dbus-daemon 1.11.14, it's only available on the `/org/freedesktop/DBus` object path.
+/// * Other bus implementations, such as dbus-broker, don't provide it.
+///
+/// If the bus doesn't provide it, [`super::Error::UnknownInterface`] or
+/// [`super::Error::UnknownMethod`] is returned, depending on the implementation.
+pub async fn get_stats(conn: &Connection) -> Result<DBusStatistics> {
+    let proxy = StatsProxy::new(conn).await?;
+
+    proxy.get_stats().await
+}
 
 /// Proxy for the `org.freedesktop.DBus.Debug.Stats` interface.
 #[proxy(
@@ -19,7 +146,7 @@ use crate::proxy;
 )]
 pub trait Stats {
     /// GetStats (undocumented)
-    fn get_stats(&self) -> Result<Vec<HashMap<String, OwnedValue>>>;
+    fn get_stats(&self) -> Result<DBusStatistics>;
 
     /// GetConnectionStats (undocumented)
     fn get_connection_stats(&self, name: BusName<'_>) -> Result<Vec<HashMap<String, OwnedValue>>>;
@@ -171,6 +171,8 @@ pub use connection::Socket;
 
 pub mod blocking;
 
+pub mod prelude;
+
 pub use zbus_macros::{interface, proxy, DBusError};
 
 // Required for the macros to function within this crate.
@@ -231,6 +233,37 @@ mod tests {
         assert_eq!(hdr.member().unwrap(), "GetMachineId");
     }
 
+    #[test]
+    #[timeout(15000)]
+    fn no_auto_start() {
+        let conn = blocking::Connection::session().unwrap();
+        let mut stream = MessageIterator::from(&conn);
+        let mut call = |auto_start| {
+            let msg = Message::method("/org/zbus/NotActivatable", "Test")
+                .unwrap()
+                .destination("org.zbus.NotActivatable")
+                .unwrap()
+                .auto_start(auto_start)
+                .build(&())
+                .unwrap();
+            let serial = msg.primary_header().serial_num();
+            conn.send(&msg).unwrap();
+
+            let reply = stream
+                .find(|m| m.as_ref().unwrap().header().reply_serial() == Some(serial))
+                .unwrap()
+                .unwrap();
+            let name = reply.header().error_name().unwrap().to_string();
+
+            name
+        };
+
+        // The bus doesn't know of any service to activate for this name..
+        assert_eq!(call(true), "org.freedesktop.DBus.Error.ServiceUnknown");
+        // ..but it shouldn't even try when asked not to.
+        assert_eq!(call(false), "org.freedesktop.DBus.Error.NameHasNoOwner");
+    }
+
     #[test]
     #[timeout(15000)]
     #[instrument]
@@ -1197,7 +1230,7 @@ mod tests {
                 let msg_data = msg.data();
                 let mut fds = vec![];
                 for _ in 0..2 {
-                    bytes.extend_from_slice(&*msg_data);
+                    bytes.extend_from_slice(msg_data);
                     fds.push(fd.as_fd());
                 }
 
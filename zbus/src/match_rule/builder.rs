@@ -129,9 +129,12 @@ impl<'m> Builder<'m> {
 
     /// Add an argument of a specified index.
     ///
+    /// The argument can contain any characters, including apostrophes and commas. They will be
+    /// escaped as needed when the rule is converted to a string.
+    ///
     /// # Errors
     ///
-    /// [`Error::InvalidMatchRule`] if `idx` is greater than 64.
+    /// [`Error::InvalidMatchRule`] if `idx` is greater than 63.
     pub fn arg<S>(mut self, idx: u8, arg: S) -> Result<Self>
     where
         S: Into<Str<'m>>,
@@ -175,7 +178,7 @@ impl<'m> Builder<'m> {
     ///
     /// # Errors
     ///
-    /// [`Error::InvalidMatchRule`] if `idx` is greater than 64.
+    /// [`Error::InvalidMatchRule`] if `idx` is greater than 63.
     pub fn arg_path<P>(mut self, idx: u8, arg_path: P) -> Result<Self>
     where
         P: TryInto<ObjectPath<'m>>,
@@ -1,6 +1,7 @@
 //! Bus match rule API.
 
 use std::{
+    borrow::Cow,
     fmt::{Display, Write},
     ops::Deref,
 };
@@ -358,16 +359,23 @@ impl Display for MatchRule<'_> {
             write_match_rule_string_component(f, key, value, &mut first_component)?;
         }
         for (i, arg) in self.args() {
-            write_comma(f, &mut first_component)?;
-            write!(f, "arg{i}='{arg}'")?;
+            write_match_rule_string_component(f, &format!("arg{i}"), arg, &mut first_component)?;
         }
         for (i, arg_path) in self.arg_paths() {
-            write_comma(f, &mut first_component)?;
-            write!(f, "arg{i}path='{arg_path}'")?;
+            write_match_rule_string_component(
+                f,
+                &format!("arg{i}path"),
+                arg_path,
+                &mut first_component,
+            )?;
         }
         if let Some(arg0namespace) = self.arg0ns() {
-            write_comma(f, &mut first_component)?;
-            write!(f, "arg0namespace='{arg0namespace}'")?;
+            write_match_rule_string_component(
+                f,
+                "arg0namespace",
+                arg0namespace,
+                &mut first_component,
+            )?;
         }
 
         Ok(())
@@ -383,7 +391,14 @@ fn write_match_rule_string_component(
     write_comma(f, first_component)?;
     f.write_str(key)?;
     f.write_str("='")?;
-    f.write_str(value)?;
+    // Apostrophes can't be escaped inside quotes, so we end the quoted part, write an escaped
+    // apostrophe and start a new quoted part.
+    for (i, part) in value.split('\'').enumerate() {
+        if i != 0 {
+            f.write_str("'\\''")?;
+        }
+        f.write_str(part)?;
+    }
     f.write_char('\'')?;
 
     Ok(())
@@ -403,24 +418,17 @@ impl<'m> TryFrom<&'m str> for MatchRule<'m> {
     type Error = Error;
 
     fn try_from(s: &'m str) -> Result<Self> {
-        let components = s.split(',');
-        if components.clone().peekable().peek().is_none() {
+        let mut builder = MatchRule::builder();
+        let mut rest = s.trim_start();
+        if rest.is_empty() {
             return Err(Error::InvalidMatchRule);
         }
-        let mut builder = MatchRule::builder();
-        for component in components {
-            let (key, value) = component.split_once('=').ok_or(Error::InvalidMatchRule)?;
-            if key.is_empty()
-                || value.len() < 2
-                || !value.starts_with('\'')
-                || !value.ends_with('\'')
-            {
-                return Err(Error::InvalidMatchRule);
-            }
-            let value = &value[1..value.len() - 1];
+        while !rest.is_empty() {
+            let (key, value, remaining) = parse_component(rest)?;
+            rest = remaining.trim_start();
             builder = match key {
                 "type" => {
-                    let msg_type = match value {
+                    let msg_type = match &*value {
                         "error" => Type::Error,
                         "method_call" => Type::MethodCall,
                         "method_return" => Type::MethodReturn,
@@ -436,20 +444,24 @@ impl<'m> TryFrom<&'m str> for MatchRule<'m> {
                 "path_namespace" => builder.path_namespace(value)?,
                 "destination" => builder.destination(value)?,
                 "arg0namespace" => builder.arg0ns(value)?,
-                key if key.starts_with("arg") => {
-                    if let Some(trailing_idx) = key.find("path") {
-                        let idx = key[3..trailing_idx]
-                            .parse::<u8>()
-                            .map_err(|_| Error::InvalidMatchRule)?;
-                        builder.arg_path(idx, value)?
-                    } else {
-                        let idx = key[3..]
-                            .parse::<u8>()
-                            .map_err(|_| Error::InvalidMatchRule)?;
-                        builder.arg(idx, value)?
+                key => match key.strip_prefix("arg") {
+                    Some(arg) => {
+                        let (idx, is_path) = match arg.strip_suffix("path") {
+                            Some(idx) => (idx, true),
+                            None => (arg, false),
+                        };
+                        if idx.is_empty() || !idx.bytes().all(|b| b.is_ascii_digit()) {
+                            return Err(Error::InvalidMatchRule);
+                        }
+                        let idx = idx.parse::<u8>().map_err(|_| Error::InvalidMatchRule)?;
+                        if is_path {
+                            builder.arg_path(idx, value)?
+                        } else {
+                            builder.arg(idx, value)?
+                        }
                     }
-                }
-                _ => return Err(Error::InvalidMatchRule),
+                    None => return Err(Error::InvalidMatchRule),
+                },
             };
         }
 
@@ -457,6 +469,58 @@ impl<'m> TryFrom<&'m str> for MatchRule<'m> {
     }
 }
 
+/// Parse the first `key=value` component of a match rule string.
+///
+/// Returns the key, the unescaped value and the rest of the string after the separating comma.
+/// The value is parsed the same way as the reference implementation: inside single quotes every
+/// character is taken literally, and outside them, `\'` is an escaped apostrophe and a comma ends
+/// the value.
+fn parse_component(s: &str) -> Result<(&str, Cow<'_, str>, &str)> {
+    let (key, value) = s.split_once('=').ok_or(Error::InvalidMatchRule)?;
+    let key = key.trim_end();
+    if key.is_empty() || !key.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_') {
+        return Err(Error::InvalidMatchRule);
+    }
+
+    // Fast path for the common case of a value that is entirely quoted, so we can borrow it.
+    if let Some(quoted) = value.strip_prefix('\'') {
+        if let Some((inner, rest)) = quoted.split_once('\'') {
+            if rest.is_empty() {
+                return Ok((key, Cow::Borrowed(inner), rest));
+            }
+            if let Some(rest) = rest.strip_prefix(',') {
+                return Ok((key, Cow::Borrowed(inner), rest));
+            }
+        }
+    }
+
+    let mut unescaped = String::new();
+    let mut in_quotes = false;
+    let mut chars = value.char_indices().peekable();
+    let mut rest = "";
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\'' => in_quotes = !in_quotes,
+            _ if in_quotes => unescaped.push(c),
+            ',' => {
+                rest = &value[i + 1..];
+                break;
+            }
+            '\\' if matches!(chars.peek(), Some((_, '\''))) => {
+                chars.next();
+                unescaped.push('\'');
+            }
+            _ => unescaped.push(c),
+        }
+    }
+    if in_quotes {
+        // Unbalanced quotes.
+        return Err(Error::InvalidMatchRule);
+    }
+
+    Ok((key, Cow::Owned(unescaped), rest))
+}
+
 impl<'de: 'm, 'm> Deserialize<'de> for MatchRule<'m> {
     fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
     where
@@ -576,3 +640,77 @@ impl PartialEq<MatchRule<'_>> for OwnedMatchRule {
         self.0 == *other
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::MatchRule;
+    use crate::Error;
+    use test_log::test;
+
+    #[test]
+    fn args_round_trip() {
+        for arg in [
+            "",
+            "'",
+            "''",
+            "don't",
+            "'quoted'",
+            "a,b,c",
+            "',',",
+            "back\\slash",
+            "\\'",
+            "ünïcödé ☃",
+            "=key='value'",
+        ] {
+            let rule = MatchRule::builder()
+                .member("Changed")
+                .unwrap()
+                .arg(3, arg)
+                .unwrap()
+                .arg0ns("org.zbus")
+                .unwrap()
+                .build();
+            let rule_str = rule.to_string();
+            let parsed = MatchRule::try_from(rule_str.as_str()).unwrap();
+            assert_eq!(parsed, rule, "failed to round-trip {rule_str}");
+        }
+    }
+
+    #[test]
+    fn parse_escaped() {
+        let rule =
+            MatchRule::try_from("arg0='don'\\''t',arg1=\\',arg2=x\\y, member ='Foo'").unwrap();
+        assert_eq!(rule.args()[0].1, "don't");
+        assert_eq!(rule.args()[1].1, "'");
+        assert_eq!(rule.args()[2].1, "x\\y");
+        assert_eq!(rule.member().unwrap(), "Foo");
+        assert_eq!(
+            rule.to_string(),
+            "member='Foo',arg0='don'\\''t',arg1=''\\''',arg2='x\\y'"
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        for rule in [
+            "",
+            "member",
+            "member='Foo",
+            "arg0='unbalanced",
+            "arg64='out of range'",
+            "arg='no index'",
+            "arg+1='sign'",
+            "arg1namespace='only for arg0'",
+            "argpath='/no/index'",
+            "foo='unknown key'",
+            "type='unknown'",
+        ] {
+            assert!(
+                matches!(MatchRule::try_from(rule), Err(Error::InvalidMatchRule)),
+                "{rule} should be invalid: {:?}",
+                MatchRule::try_from(rule),
+            );
+        }
+        MatchRule::try_from("arg0path='not a path'").unwrap_err();
+    }
+}
@@ -27,6 +27,9 @@ impl Body {
     }
 
     /// Deserialize the body using the contained signature.
+    ///
+    /// A message without a body is represented by the unit type, `()`. So deserializing to `()`
+    /// succeeds if, and only if, the body is empty.
     pub fn deserialize<'s, B>(&'s self) -> Result<B>
     where
         B: zvariant::DynamicDeserialize<'s>,
@@ -68,6 +71,11 @@ impl Body {
     }
 
     /// Get a reference to the underlying byte encoding of the message.
+    ///
+    /// The body is encoded exactly as [`zvariant::to_bytes`] would encode it in the D-Bus format,
+    /// with the endianness of the message and a starting position of `0`, since the body always
+    /// starts on an 8-byte boundary. So the same encoding can be produced and decoded without a
+    /// message, through [`zvariant::to_bytes`] and [`zvariant::serialized::Data::deserialize`].
     pub fn data(&self) -> &serialized::Data<'static, 'static> {
         &self.data
     }
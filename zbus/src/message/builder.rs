@@ -1,5 +1,6 @@
 use std::{
     io::{Cursor, Write},
+    num::NonZeroU32,
     sync::Arc,
 };
 #[cfg(unix)]
@@ -105,6 +106,44 @@ impl<'a> Builder<'a> {
         Ok(self)
     }
 
+    /// Set whether the bus may launch an owner for the destination name.
+    ///
+    /// By default, if the destination of a method call is a name that is not currently owned but
+    /// for which the bus knows a service that can be activated, the bus starts that service and
+    /// delivers the message to it once it owns the name. Passing `false` sets the
+    /// [`Flags::NoAutoStart`] flag, in which case the bus instead replies with an error if the
+    /// name has no owner (`org.freedesktop.DBus.Error.NameHasNoOwner` with the reference bus
+    /// implementation).
+    pub fn auto_start(mut self, auto_start: bool) -> Self {
+        let mut flags = self.header.primary().flags();
+        if auto_start {
+            flags.remove(Flags::NoAutoStart);
+        } else {
+            flags.insert(Flags::NoAutoStart);
+        }
+        self.header.primary_mut().set_flags(flags);
+
+        self
+    }
+
+    /// Set whether the caller is prepared to wait for interactive authorization.
+    ///
+    /// Passing `true` sets the [`Flags::AllowInteractiveAuth`] flag, allowing the receiver to
+    /// prompt the user for authorization (e.g. through polkit) before carrying out the method call,
+    /// which may take a considerable time. By default, the flag is not set and the receiver is
+    /// expected to fail the call if authorization can't be granted without user interaction.
+    pub fn allow_interactive_auth(mut self, allow: bool) -> Self {
+        let mut flags = self.header.primary().flags();
+        if allow {
+            flags.insert(Flags::AllowInteractiveAuth);
+        } else {
+            flags.remove(Flags::AllowInteractiveAuth);
+        }
+        self.header.primary_mut().set_flags(flags);
+
+        self
+    }
+
     /// Set the unique name of the sending connection.
     pub fn sender<'s: 'a, S>(mut self, sender: S) -> Result<Self>
     where
@@ -186,6 +225,21 @@ impl<'a> Builder<'a> {
         self
     }
 
+    /// Set the serial number of the message.
+    ///
+    /// By default, each message gets a new serial number, unique within the process. Overriding
+    /// it is mostly useful in tests, to get the exact same bytes when building the same message
+    /// twice. The serial number can't be 0, as per the specification.
+    ///
+    /// Note that the peer uses the serial number to identify a method call when replying to it,
+    /// so it's up to the caller to ensure serial numbers are not reused when sending messages on
+    /// a connection.
+    pub fn serial(mut self, serial: NonZeroU32) -> Self {
+        self.header.primary_mut().set_serial_num(serial);
+
+        self
+    }
+
     /// Build the [`Message`] with the given body.
     ///
     /// You may pass `()` as the body if the message has no body.
@@ -363,4 +417,40 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn serial() -> Result<(), Error> {
+        let serial = 42.try_into().unwrap();
+        let build = || {
+            Message::method("/org/zbus/Serial", "Ping")?
+                .serial(serial)
+                .build(&("foo", 42u32))
+        };
+        let (a, b) = (build()?, build()?);
+        assert_eq!(a.primary_header().serial_num(), serial);
+        assert_eq!(a.data().bytes(), b.data().bytes());
+
+        Ok(())
+    }
+
+    #[test]
+    fn allow_interactive_auth() -> Result<(), Error> {
+        let msg = Message::method("/org/zbus/Auth", "Ping")?.build(&())?;
+        assert_eq!(msg.data()[2], 0);
+        let msg = Message::method("/org/zbus/Auth", "Ping")?
+            .allow_interactive_auth(true)
+            .build(&())?;
+        assert_eq!(msg.data()[2], 0x4);
+        assert!(msg
+            .primary_header()
+            .flags()
+            .contains(crate::message::Flags::AllowInteractiveAuth));
+        let msg = Message::method("/org/zbus/Auth", "Ping")?
+            .allow_interactive_auth(true)
+            .allow_interactive_auth(false)
+            .build(&())?;
+        assert_eq!(msg.data()[2], 0);
+
+        Ok(())
+    }
 }
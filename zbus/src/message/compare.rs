@@ -0,0 +1,368 @@
+use std::fmt;
+
+use static_assertions::assert_impl_all;
+use zvariant::{serialized, Endian, OwnedValue, Value};
+
+use crate::{
+    message::{
+        header::{message_layout, MIN_MESSAGE_SIZE},
+        EndianSig, PrimaryHeader,
+    },
+    utils::padding_for_8_bytes,
+    Error, Result,
+};
+
+/// A divergence between two encoded messages, as reported by [`compare_wire`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Difference {
+    name: &'static str,
+    offsets: (usize, usize),
+    left: Option<String>,
+    right: Option<String>,
+}
+
+assert_impl_all!(Difference: Send, Sync, Unpin);
+
+impl Difference {
+    /// The name of the diverging part of the message, e.g. `"serial"` or `"member"`.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The byte offsets of the diverging part in the first and second message respectively.
+    pub fn offsets(&self) -> (usize, usize) {
+        self.offsets
+    }
+
+    /// The value in the first message, or `None` if it's absent from it.
+    pub fn left(&self) -> Option<&str> {
+        self.left.as_deref()
+    }
+
+    /// The value in the second message, or `None` if it's absent from it.
+    pub fn right(&self) -> Option<&str> {
+        self.right.as_deref()
+    }
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (left_offset, right_offset) = self.offsets;
+        write!(
+            f,
+            "{} differs: {} at offset {} vs. {} at offset {}",
+            self.name,
+            self.left.as_deref().unwrap_or("<none>"),
+            left_offset,
+            self.right.as_deref().unwrap_or("<none>"),
+            right_offset,
+        )
+    }
+}
+
+/// Compare two encoded messages.
+///
+/// Both messages are decoded in lockstep and each divergence in the primary header and the header
+/// fields is reported, along with the first diverging byte of the body. Header fields are compared
+/// by their code, regardless of their position, and a separate `"field order"` difference is
+/// reported if they are not emitted in the same order. This makes it possible to compare messages
+/// encoded by zbus against captures of messages encoded by other D-Bus implementations.
+///
+/// An empty list is returned if both messages are identical.
+///
+/// # Errors
+///
+/// If either of the messages can't be decoded.
+///
+/// # Examples
+///
+/// ```
+/// # use zbus::message::{compare_wire, Message};
+/// let a = Message::method("/org/zbus/Compare", "Ping")?.build(&())?;
+/// let b = Message::method("/org/zbus/Compare", "Pong")?.build(&())?;
+///
+/// let diffs = compare_wire(a.data(), a.data())?;
+/// assert!(diffs.is_empty());
+///
+/// let diffs = compare_wire(a.data(), b.data())?;
+/// let names: Vec<_> = diffs.iter().map(|d| d.name()).collect();
+/// assert_eq!(names, ["serial", "member"]);
+/// assert_eq!(diffs[1].left(), Some("\"Ping\""));
+/// assert_eq!(diffs[1].right(), Some("\"Pong\""));
+/// # Ok::<(), zbus::Error>(())
+/// ```
+pub fn compare_wire(a: &[u8], b: &[u8]) -> Result<Vec<Difference>> {
+    let a = Decoded::new(a)?;
+    let b = Decoded::new(b)?;
+    let mut diffs = vec![];
+    let mut add = |name, offsets, left: String, right: String| {
+        if left != right {
+            diffs.push(Difference {
+                name,
+                offsets,
+                left: Some(left),
+                right: Some(right),
+            });
+        }
+    };
+
+    let (ha, hb) = (&a.primary_header, &b.primary_header);
+    add(
+        "endianness",
+        (0, 0),
+        format!("{:?}", ha.endian_sig()),
+        format!("{:?}", hb.endian_sig()),
+    );
+    add(
+        "type",
+        (1, 1),
+        format!("{:?}", ha.msg_type()),
+        format!("{:?}", hb.msg_type()),
+    );
+    add(
+        "flags",
+        (2, 2),
+        format!("{:?}", ha.flags()),
+        format!("{:?}", hb.flags()),
+    );
+    add(
+        "version",
+        (3, 3),
+        ha.protocol_version().to_string(),
+        hb.protocol_version().to_string(),
+    );
+    add(
+        "body length",
+        (4, 4),
+        ha.body_len().to_string(),
+        hb.body_len().to_string(),
+    );
+    add(
+        "serial",
+        (8, 8),
+        ha.serial_num().to_string(),
+        hb.serial_num().to_string(),
+    );
+
+    for (code, offset, value) in &a.fields {
+        let other = b.fields.iter().find(|(c, _, _)| c == code);
+        diffs.extend(field_difference(
+            *code,
+            (
+                *offset,
+                other.map(|(_, o, _)| *o).unwrap_or(MIN_MESSAGE_SIZE),
+            ),
+            Some(value),
+            other.map(|(_, _, v)| &**v),
+        ));
+    }
+    for (code, offset, value) in &b.fields {
+        if !a.fields.iter().any(|(c, _, _)| c == code) {
+            diffs.extend(field_difference(
+                *code,
+                (MIN_MESSAGE_SIZE, *offset),
+                None,
+                Some(&**value),
+            ));
+        }
+    }
+    let codes_a: Vec<_> = a.fields.iter().map(|(c, _, _)| field_name(*c)).collect();
+    let codes_b: Vec<_> = b.fields.iter().map(|(c, _, _)| field_name(*c)).collect();
+    if codes_a.len() == codes_b.len()
+        && codes_a.iter().all(|c| codes_b.contains(c))
+        && codes_a != codes_b
+    {
+        diffs.push(Difference {
+            name: "field order",
+            offsets: (MIN_MESSAGE_SIZE, MIN_MESSAGE_SIZE),
+            left: Some(codes_a.join(", ")),
+            right: Some(codes_b.join(", ")),
+        });
+    }
+
+    let (body_a, body_b) = (a.body(), b.body());
+    if body_a != body_b {
+        let pos = body_a
+            .iter()
+            .zip(body_b)
+            .position(|(x, y)| x != y)
+            .unwrap_or_else(|| body_a.len().min(body_b.len()));
+        let byte = |body: &[u8]| body.get(pos).map(|b| format!("{b:#04x}"));
+        diffs.push(Difference {
+            name: "body",
+            offsets: (a.body_offset + pos, b.body_offset + pos),
+            left: byte(body_a),
+            right: byte(body_b),
+        });
+    }
+
+    Ok(diffs)
+}
+
+fn field_difference(
+    code: u8,
+    offsets: (usize, usize),
+    left: Option<&Value<'_>>,
+    right: Option<&Value<'_>>,
+) -> Option<Difference> {
+    if left == right {
+        return None;
+    }
+
+    Some(Difference {
+        name: field_name(code),
+        offsets,
+        left: left.map(ToString::to_string),
+        right: right.map(ToString::to_string),
+    })
+}
+
+fn field_name(code: u8) -> &'static str {
+    match code {
+        1 => "path",
+        2 => "interface",
+        3 => "member",
+        4 => "error name",
+        5 => "reply serial",
+        6 => "destination",
+        7 => "sender",
+        8 => "signature",
+        9 => "unix fds",
+        _ => "unknown field",
+    }
+}
+
+/// A message decoded just enough for comparison, keeping track of the offsets of its parts.
+struct Decoded<'b> {
+    bytes: &'b [u8],
+    primary_header: PrimaryHeader,
+    fields: Vec<(u8, usize, OwnedValue)>,
+    body_offset: usize,
+    total_len: usize,
+}
+
+impl<'b> Decoded<'b> {
+    fn new(bytes: &'b [u8]) -> Result<Self> {
+        if bytes.len() < MIN_MESSAGE_SIZE {
+            return Err(truncated());
+        }
+        let (primary_header, fields_len) = PrimaryHeader::read(bytes)?;
+        let (body_offset, total_len) = message_layout(fields_len, primary_header.body_len())?;
+        if bytes.len() < total_len {
+            return Err(truncated());
+        }
+
+        let endian = Endian::from(EndianSig::try_from(bytes[0])?);
+        let ctxt = serialized::Context::new_dbus(endian, 0);
+        let header_len = MIN_MESSAGE_SIZE + fields_len as usize;
+        let data = serialized::Data::new(&bytes[..header_len], ctxt);
+        let mut fields = vec![];
+        let mut pos = MIN_MESSAGE_SIZE;
+        while pos < header_len {
+            pos += padding_for_8_bytes(pos);
+            if pos > header_len {
+                // The fields length doesn't match the fields.
+                return Err(truncated());
+            }
+            let field = data.slice(pos..);
+            let ((code, value), len) = field.deserialize::<(u8, Value<'_>)>()?;
+            fields.push((code, pos, value.try_to_owned()?));
+            pos += len;
+        }
+
+        Ok(Self {
+            bytes,
+            primary_header,
+            fields,
+            body_offset,
+            total_len,
+        })
+    }
+
+    fn body(&self) -> &'b [u8] {
+        &self.bytes[self.body_offset..self.total_len]
+    }
+}
+
+fn truncated() -> Error {
+    Error::InputOutput(
+        std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "truncated message").into(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::compare_wire;
+    use crate::message::Message;
+
+    #[test]
+    fn body() {
+        let a = Message::signal("/org/zbus/Compare", "org.zbus.Compare", "Changed")
+            .unwrap()
+            .build(&(1u32, "foo"))
+            .unwrap();
+        let b = Message::signal("/org/zbus/Compare", "org.zbus.Compare", "Changed")
+            .unwrap()
+            .build(&(1u32, "fob"))
+            .unwrap();
+
+        // Each message gets a new serial.
+        let diffs = compare_wire(a.data(), b.data()).unwrap();
+        let names: Vec<_> = diffs.iter().map(|d| d.name()).collect();
+        assert_eq!(names, ["serial", "body"]);
+        let diff = &diffs[1];
+        assert_eq!(diff.name(), "body");
+        let offset = a.data().len() - 2;
+        assert_eq!(diff.offsets(), (offset, offset));
+        assert_eq!(diff.left(), Some("0x6f"));
+        assert_eq!(diff.right(), Some("0x62"));
+
+        // A missing field is reported as such and shifts the body.
+        let c = Message::signal("/org/zbus/Compare", "org.zbus.Compare", "Changed")
+            .unwrap()
+            .destination(":1.42")
+            .unwrap()
+            .build(&(1u32, "foo"))
+            .unwrap();
+        let diffs = compare_wire(a.data(), c.data()).unwrap();
+        let names: Vec<_> = diffs.iter().map(|d| d.name()).collect();
+        assert_eq!(names, ["serial", "destination"]);
+        assert_eq!(diffs[1].left(), None);
+        assert_eq!(diffs[1].right(), Some("\":1.42\""));
+
+        compare_wire(a.data(), &c.data()[..20]).unwrap_err();
+    }
+
+    #[test]
+    fn malformed_fields_len() {
+        // The last header field, the `usy` signature, doesn't end on an 8-byte boundary.
+        let msg = Message::signal("/org/zbus/Compare", "org.zbus.Compare", "Changed")
+            .unwrap()
+            .build(&(1u32, "foo", 2u8))
+            .unwrap();
+        let bytes = msg.data().to_vec();
+        let little_endian = bytes[0] == b'l';
+        let fields_len: [u8; 4] = bytes[12..16].try_into().unwrap();
+        let fields_len = if little_endian {
+            u32::from_le_bytes(fields_len)
+        } else {
+            u32::from_be_bytes(fields_len)
+        };
+
+        // Claim more header fields than there are, so that the padding before the next field runs
+        // past the end of the fields.
+        for extra in 1..8 {
+            let mut malformed = bytes.clone();
+            let len = fields_len + extra;
+            let len = if little_endian {
+                len.to_le_bytes()
+            } else {
+                len.to_be_bytes()
+            };
+            malformed[12..16].copy_from_slice(&len);
+            malformed.extend_from_slice(&[0; 16]);
+
+            compare_wire(&bytes, &malformed).unwrap_err();
+        }
+    }
+}
@@ -14,12 +14,34 @@ use zvariant::{
     Endian, ObjectPath, Signature, Type as VariantType,
 };
 
-use crate::{message::Fields, Error};
+use crate::{message::Fields, utils::padding_for_8_bytes, Error};
 
 pub(crate) const PRIMARY_HEADER_SIZE: usize = 12;
 pub(crate) const MIN_MESSAGE_SIZE: usize = PRIMARY_HEADER_SIZE + 4;
 pub(crate) const MAX_MESSAGE_SIZE: usize = 128 * 1024 * 1024; // 128 MiB
 
+/// Compute the offset of the body and the total length of a message, given the length of its
+/// header fields and its body, as read from the primary header.
+///
+/// Returns [`Error::ExcessData`] if the message would be larger than the maximum allowed size.
+/// All arithmetic is checked, so this never overflows even if `usize` is 32-bit.
+pub(crate) fn message_layout(fields_len: u32, body_len: u32) -> Result<(usize, usize), Error> {
+    let header_len = usize::try_from(fields_len)
+        .ok()
+        .and_then(|len| len.checked_add(MIN_MESSAGE_SIZE))
+        .ok_or(Error::ExcessData)?;
+    let body_offset = header_len
+        .checked_add(padding_for_8_bytes(header_len))
+        .ok_or(Error::ExcessData)?;
+    let total_len = usize::try_from(body_len)
+        .ok()
+        .and_then(|len| len.checked_add(body_offset))
+        .filter(|len| *len <= MAX_MESSAGE_SIZE)
+        .ok_or(Error::ExcessData)?;
+
+    Ok((body_offset, total_len))
+}
+
 /// D-Bus code for endianness.
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, Deserialize_repr, PartialEq, Eq, Serialize_repr, VariantType)]
@@ -330,6 +352,8 @@ static SERIAL_NUM: AtomicU32 = AtomicU32::new(0);
 mod tests {
     use crate::message::{Fields, Header, PrimaryHeader, Type};
 
+    use super::{message_layout, MAX_MESSAGE_SIZE, MIN_MESSAGE_SIZE};
+
     use std::error::Error;
     use test_log::test;
     use zbus_names::{InterfaceName, MemberName};
@@ -379,4 +403,27 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn layout() {
+        assert_eq!(message_layout(0, 0).unwrap(), (16, 16));
+        assert_eq!(message_layout(1, 4).unwrap(), (24, 28));
+
+        let max_body = (MAX_MESSAGE_SIZE - MIN_MESSAGE_SIZE) as u32;
+        assert_eq!(
+            message_layout(0, max_body).unwrap(),
+            (MIN_MESSAGE_SIZE, MAX_MESSAGE_SIZE)
+        );
+        assert_eq!(
+            message_layout(0, max_body + 1).unwrap_err(),
+            crate::Error::ExcessData
+        );
+        // Lengths near `u32::MAX` would overflow a 32-bit `usize` when added up.
+        for (fields_len, body_len) in [(u32::MAX, 0), (0, u32::MAX), (u32::MAX, u32::MAX)] {
+            assert_eq!(
+                message_layout(fields_len, body_len).unwrap_err(),
+                crate::Error::ExcessData
+            );
+        }
+    }
 }
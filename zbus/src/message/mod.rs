@@ -5,7 +5,7 @@ use static_assertions::assert_impl_all;
 use zbus_names::{ErrorName, InterfaceName, MemberName};
 use zvariant::{serialized, Endian};
 
-use crate::{utils::padding_for_8_bytes, zvariant::ObjectPath, Error, Result};
+use crate::{zvariant::ObjectPath, Error, Result};
 
 mod builder;
 pub use builder::Builder;
@@ -20,9 +20,17 @@ use fields::QuickFields;
 mod body;
 pub use body::Body;
 
+mod compare;
+pub use compare::{compare_wire, Difference};
+
 pub(crate) mod header;
+use header::PRIMARY_HEADER_SIZE;
 pub use header::{EndianSig, Flags, Header, PrimaryHeader, Type, NATIVE_ENDIAN_SIG};
-use header::{MIN_MESSAGE_SIZE, PRIMARY_HEADER_SIZE};
+
+pub use zvariant::{
+    signature::{MAX_ARRAY_DEPTH, MAX_STRUCT_DEPTH, MAX_TOTAL_DEPTH},
+    MAX_ARRAY_LEN,
+};
 
 /// A position in the stream of [`Message`] objects received by a single [`zbus::Connection`].
 ///
@@ -143,8 +151,7 @@ impl Message {
         let (fields, _) = fields_bytes.deserialize()?;
         let header = Header::new(primary_header.clone(), fields);
 
-        let header_len = MIN_MESSAGE_SIZE + fields_len as usize;
-        let body_offset = header_len + padding_for_8_bytes(header_len);
+        let (body_offset, _) = header::message_layout(fields_len, primary_header.body_len())?;
         let quick_fields = QuickFields::new(&bytes, &header).into();
 
         Ok(Self {
@@ -421,4 +428,117 @@ mod tests {
             .unwrap();
         assert_eq!(e.to_string(), "Error org.freedesktop.zbus.Error: kaboom!");
     }
+
+    #[test]
+    fn auto_start() {
+        use crate::message::Flags;
+
+        let m = Message::method("/", "do").unwrap().build(&()).unwrap();
+        assert!(!m.primary_header().flags().contains(Flags::NoAutoStart));
+
+        let m = Message::method("/", "do")
+            .unwrap()
+            .with_flags(Flags::NoReplyExpected)
+            .unwrap()
+            .auto_start(false)
+            .build(&())
+            .unwrap();
+        let flags = m.primary_header().flags();
+        assert!(flags.contains(Flags::NoAutoStart));
+        assert!(flags.contains(Flags::NoReplyExpected));
+        assert_eq!(m.data()[2], 0x3);
+
+        let m = Message::method("/", "do")
+            .unwrap()
+            .auto_start(false)
+            .auto_start(true)
+            .build(&())
+            .unwrap();
+        assert!(!m.primary_header().flags().contains(Flags::NoAutoStart));
+    }
+
+    #[test]
+    fn unit_body() {
+        // `()` means no body at all, and hence no signature field.
+        let m = Message::method("/", "do").unwrap().build(&()).unwrap();
+        assert!(m.header().signature().is_none());
+        assert!(m.body().is_empty());
+        m.body().deserialize::<()>().unwrap();
+        assert!(m.body().deserialize::<u32>().is_err());
+
+        let m = Message::method("/", "do").unwrap().build(&(7u32,)).unwrap();
+        assert_eq!(m.header().signature().unwrap(), "u");
+        assert!(m.body().deserialize::<()>().is_err());
+
+        // The empty struct is not a valid signature.
+        assert!(Signature::try_from("()").is_err());
+    }
+
+    #[test]
+    fn body_marshalling() {
+        use std::collections::HashMap;
+        use zvariant::{serialized::Context, to_bytes, Endian, Value};
+
+        let dict = HashMap::from([("answer", Value::from(42u8))]);
+        let body = (7u32, "foo", vec![1u16, 2, 3], dict);
+        let m = Message::method("/", "do").unwrap().build(&body).unwrap();
+
+        // The body of a message is plain zvariant encoding, so it can be (de)serialized without
+        // constructing a message.
+        let endian = Endian::from(m.primary_header().endian_sig());
+        let ctxt = Context::new_dbus(endian, 0);
+        let encoded = to_bytes(ctxt, &body).unwrap();
+        assert_eq!(encoded.bytes(), m.body().data().bytes());
+
+        let decoded: (u32, String, Vec<u16>, HashMap<String, Value<'_>>) =
+            encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, m.body().deserialize().unwrap());
+    }
+
+    #[test]
+    fn array_too_large() {
+        let build = |len| {
+            Message::signal("/org/zbus/Array", "org.zbus.Array", "Data")
+                .unwrap()
+                .build(&(vec![0u8; len],))
+        };
+
+        build(super::MAX_ARRAY_LEN).unwrap();
+        assert_eq!(
+            build(super::MAX_ARRAY_LEN + 1).unwrap_err(),
+            Error::Variant(zvariant::Error::ArrayTooLarge(super::MAX_ARRAY_LEN + 1))
+        );
+    }
+
+    #[test]
+    fn struct_too_deep() {
+        let build = |depth| {
+            let mut s = zvariant::StructureBuilder::new()
+                .add_field(0u8)
+                .build()
+                .unwrap();
+            for _ in 1..depth {
+                s = zvariant::StructureBuilder::new()
+                    .add_field(s)
+                    .build()
+                    .unwrap();
+            }
+            Message::signal("/org/zbus/Struct", "org.zbus.Struct", "Data")
+                .unwrap()
+                .build(&s)
+        };
+
+        build(super::MAX_STRUCT_DEPTH.into()).unwrap();
+        assert_eq!(
+            build(usize::from(super::MAX_STRUCT_DEPTH) + 1).unwrap_err(),
+            Error::Variant(zvariant::Error::MaxDepthExceeded(
+                zvariant::MaxDepthExceeded::Structure
+            ))
+        );
+
+        // Nor can a signature be that deep, whether it's in a message header or anywhere else.
+        let depth = usize::from(super::MAX_STRUCT_DEPTH) + 1;
+        let signature = format!("{}y{}", "(".repeat(depth), ")".repeat(depth));
+        Signature::try_from(signature.as_str()).unwrap_err();
+    }
 }
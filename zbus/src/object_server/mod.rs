@@ -427,6 +427,14 @@ impl ObjectServer {
     pub(crate) async fn dispatch_call(&self, msg: &Message, hdr: &Header<'_>) -> Result<()> {
         let conn = self.connection();
 
+        #[cfg(unix)]
+        if let Err(e) = conn.check_peer_uid(hdr).await {
+            debug!("Rejecting method call: {}", e);
+            conn.reply_dbus_error(hdr, e).await?;
+
+            return Ok(());
+        }
+
         if let Err(e) = self.dispatch_method_call_try(&conn, msg, hdr).await {
             debug!("Returning error: {}", e);
             conn.reply_dbus_error(hdr, e).await?;
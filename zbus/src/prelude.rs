@@ -0,0 +1,40 @@
+//! The most commonly used types, traits and macros.
+//!
+//! Glob-importing this module saves listing them individually in each file:
+//!
+//! ```no_run
+//! use zbus::prelude::*;
+//!
+//! #[proxy(
+//!     interface = "org.freedesktop.DBus",
+//!     default_service = "org.freedesktop.DBus",
+//!     default_path = "/org/freedesktop/DBus"
+//! )]
+//! trait Bus {
+//!     fn get_name_owner(&self, name: BusName<'_>) -> zbus::Result<OwnedUniqueName>;
+//! }
+//!
+//! # zbus::block_on(async {
+//! let connection = Connection::session().await?;
+//! let proxy = BusProxy::new(&connection).await?;
+//! let owner = proxy.get_name_owner("org.freedesktop.DBus".try_into()?).await?;
+//! println!("{owner}");
+//! # Ok::<(), zbus::Error>(())
+//! # }).unwrap();
+//! ```
+//!
+//! `zbus::Result` and `zbus::Error` are deliberately left out, so they don't shadow their
+//! counterparts from the standard library.
+
+pub use crate::{
+    interface,
+    names::{
+        BusName, ErrorName, InterfaceName, MemberName, OwnedBusName, OwnedErrorName,
+        OwnedInterfaceName, OwnedMemberName, OwnedUniqueName, OwnedWellKnownName, UniqueName,
+        WellKnownName,
+    },
+    object_server::SignalContext,
+    proxy,
+    zvariant::{ObjectPath, OwnedObjectPath, OwnedValue, Type, Value},
+    Connection, Message, ObjectServer, Proxy,
+};
@@ -1,4 +1,4 @@
-use std::{collections::HashSet, marker::PhantomData, sync::Arc};
+use std::{collections::HashSet, marker::PhantomData, sync::Arc, time::Duration};
 
 use static_assertions::assert_impl_all;
 use zbus_names::{BusName, InterfaceName};
@@ -30,6 +30,7 @@ pub struct Builder<'a, T = ()> {
     proxy_type: PhantomData<T>,
     cache: CacheProperties,
     uncached_properties: Option<HashSet<Str<'a>>>,
+    method_timeout: Option<Duration>,
 }
 
 impl<'a, T> Clone for Builder<'a, T> {
@@ -41,6 +42,7 @@ impl<'a, T> Clone for Builder<'a, T> {
             interface: self.interface.clone(),
             cache: self.cache,
             uncached_properties: self.uncached_properties.clone(),
+            method_timeout: self.method_timeout,
             proxy_type: PhantomData,
         }
     }
@@ -95,6 +97,16 @@ impl<'a, T> Builder<'a, T> {
         self
     }
 
+    /// Set the maximum time to wait for the reply to a method call.
+    ///
+    /// This overrides the connection-wide [`crate::connection::Builder::method_timeout`] for calls
+    /// made through this proxy.
+    #[must_use]
+    pub fn method_timeout(mut self, timeout: Duration) -> Self {
+        self.method_timeout = Some(timeout);
+        self
+    }
+
     pub(crate) fn build_internal(self) -> Result<Proxy<'a>> {
         let conn = self.conn;
         let destination = self
@@ -113,6 +125,7 @@ impl<'a, T> Builder<'a, T> {
                 interface,
                 cache,
                 uncached_properties,
+                self.method_timeout,
             )),
         })
     }
@@ -158,6 +171,7 @@ where
                 .map(|i| InterfaceName::from_static_str(i).expect("invalid interface name")),
             cache: CacheProperties::default(),
             uncached_properties: None,
+            method_timeout: None,
             proxy_type: PhantomData,
         }
     }
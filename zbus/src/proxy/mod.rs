@@ -14,6 +14,7 @@ use std::{
     pin::Pin,
     sync::{Arc, OnceLock, RwLock, RwLockReadGuard},
     task::{Context, Poll},
+    time::Duration,
 };
 use tracing::{debug, info_span, instrument, trace, Instrument};
 
@@ -103,6 +104,8 @@ pub(crate) struct ProxyInner<'a> {
     /// Set of properties which do not get cached, by name.
     /// This overrides proxy-level caching behavior.
     uncached_properties: HashSet<Str<'a>>,
+    /// Overrides the connection's method timeout, if set.
+    method_timeout: Option<Duration>,
 }
 
 impl Drop for ProxyInnerStatic {
@@ -494,6 +497,7 @@ impl<'a> ProxyInner<'a> {
         interface: InterfaceName<'a>,
         cache: CacheProperties,
         uncached_properties: HashSet<Str<'a>>,
+        method_timeout: Option<Duration>,
     ) -> Self {
         let property_cache = match cache {
             CacheProperties::Yes | CacheProperties::Lazily => Some(OnceLock::new()),
@@ -509,6 +513,7 @@ impl<'a> ProxyInner<'a> {
             interface,
             property_cache,
             uncached_properties,
+            method_timeout,
         }
     }
 
@@ -638,6 +643,16 @@ impl<'a> Proxy<'a> {
         &self.inner.interface
     }
 
+    /// The maximum time to wait for the reply to a method call, if any.
+    ///
+    /// This is the timeout set through [`Builder::method_timeout`] or, if none was set, the
+    /// connection's [`Connection::method_timeout`].
+    pub fn method_timeout(&self) -> Option<Duration> {
+        self.inner
+            .method_timeout
+            .or_else(|| self.connection().method_timeout())
+    }
+
     /// Introspect the associated object, and return the XML description.
     ///
     /// See the [xml](https://docs.rs/zbus_xml) crate for parsing the
@@ -799,6 +814,53 @@ impl<'a> Proxy<'a> {
         value.try_into().map_err(Into::into)
     }
 
+    /// Get all the properties of the interface at once, deserialized into `T`.
+    ///
+    /// Effectively, call the `GetAll` method of the `org.freedesktop.DBus.Properties` interface and
+    /// deserialize its reply, a `a{sv}` dictionary, into `T`. This is typically a type deriving
+    /// [`DeserializeDict`] and [`Type`], with each field corresponding to a property. The cache is
+    /// neither consulted nor updated.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use zbus::{Connection, Proxy};
+    /// use zbus::zvariant::{DeserializeDict, Type};
+    ///
+    /// #[derive(Debug, DeserializeDict, Type)]
+    /// #[zvariant(signature = "a{sv}", rename_all = "PascalCase")]
+    /// struct BusProperties {
+    ///     features: Vec<String>,
+    ///     interfaces: Vec<String>,
+    /// }
+    ///
+    /// # async_io::block_on(async {
+    /// let connection = Connection::session().await?;
+    /// let proxy = Proxy::new(
+    ///     &connection,
+    ///     "org.freedesktop.DBus",
+    ///     "/org/freedesktop/DBus",
+    ///     "org.freedesktop.DBus",
+    /// )
+    /// .await?;
+    /// let properties: BusProperties = proxy.get_all_properties().await?;
+    /// println!("{properties:?}");
+    /// # Ok::<(), zbus::Error>(())
+    /// # }).unwrap();
+    /// ```
+    ///
+    /// [`DeserializeDict`]: zvariant::DeserializeDict
+    /// [`Type`]: zvariant::Type
+    pub async fn get_all_properties<T>(&self) -> Result<T>
+    where
+        T: for<'d> zvariant::DynamicDeserialize<'d>,
+    {
+        self.properties_proxy()
+            .inner()
+            .call("GetAll", &(self.inner.interface.as_ref(),))
+            .await
+    }
+
     /// Set the property `property_name`.
     ///
     /// Effectively, call the `Set` method of the `org.freedesktop.DBus.Properties` interface.
@@ -817,7 +879,10 @@ impl<'a> Proxy<'a> {
     /// deserialize the reply message manually (this way, you can avoid the memory
     /// allocation/copying, by deserializing the reply to an unowned type).
     ///
+    /// The call fails if no reply arrives within the [method timeout], if any.
+    ///
     /// [`call`]: struct.Proxy.html#method.call
+    /// [method timeout]: Proxy::method_timeout
     pub async fn call_method<'m, M, B>(&self, method_name: M, body: &B) -> Result<Message>
     where
         M: TryInto<MemberName<'m>>,
@@ -827,13 +892,17 @@ impl<'a> Proxy<'a> {
         self.inner
             .inner_without_borrows
             .conn
-            .call_method(
+            .call_method_raw(
                 Some(&self.inner.destination),
                 self.inner.path.as_str(),
                 Some(&self.inner.interface),
                 method_name,
+                BitFlags::empty(),
                 body,
             )
+            .await?
+            .expect("no reply")
+            .reply(self.method_timeout())
             .await
     }
 
@@ -890,7 +959,12 @@ impl<'a> Proxy<'a> {
             )
             .await?
         {
-            Some(reply) => reply.await?.body().deserialize().map(Some),
+            Some(reply) => reply
+                .reply(self.method_timeout())
+                .await?
+                .body()
+                .deserialize()
+                .map(Some),
             None => Ok(None),
         }
     }
@@ -960,6 +1034,42 @@ impl<'a> Proxy<'a> {
         self.receive_signals(None, &[]).await
     }
 
+    /// Call `callback` for each signal named `signal_name`.
+    ///
+    /// This is an alternative to [`Proxy::receive_signal`] for when a callback is more convenient
+    /// than a stream. The signals are received in a task, spawned on the executor of the
+    /// connection. The callback is disconnected when the returned [`Task`] is dropped, so use
+    /// [`Task::detach`] to keep it connected for the lifetime of the connection instead.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Proxy::receive_signal`].
+    pub async fn connect_signal<'m, M, F>(
+        &self,
+        signal_name: M,
+        mut callback: F,
+    ) -> Result<Task<()>>
+    where
+        M: TryInto<MemberName<'m>>,
+        M::Error: Into<Error>,
+        F: FnMut(Message) + Send + 'static,
+    {
+        use futures_util::StreamExt;
+
+        let signal_name = signal_name.try_into().map_err(Into::into)?.into_owned();
+        let mut stream = self.receive_signal(signal_name).await?;
+        let task = self.inner.inner_without_borrows.conn.executor().spawn(
+            async move {
+                while let Some(msg) = stream.next().await {
+                    callback(msg);
+                }
+            },
+            "signal handler",
+        );
+
+        Ok(task)
+    }
+
     /// Get a stream to receive property changed events.
     ///
     /// Note that zbus doesn't queue the updates. If the listener is slower than the receiver, it
@@ -1541,4 +1651,72 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    #[timeout(15000)]
+    fn get_all_properties() {
+        block_on(test_get_all_properties()).unwrap();
+    }
+
+    async fn test_get_all_properties() -> Result<()> {
+        use zvariant::{DeserializeDict, Type};
+
+        #[derive(Debug, DeserializeDict, Type)]
+        #[zvariant(signature = "a{sv}", rename_all = "PascalCase")]
+        struct BusProperties {
+            features: Vec<String>,
+            interfaces: Vec<crate::names::OwnedInterfaceName>,
+        }
+
+        let conn = Connection::session().await?;
+        let proxy = fdo::DBusProxy::new(&conn).await?;
+        let properties: BusProperties = proxy.inner().get_all_properties().await?;
+        assert_eq!(properties.features, proxy.features().await?);
+        assert_eq!(properties.interfaces, proxy.interfaces().await?);
+
+        // The reply must match the signature of `T`.
+        proxy
+            .inner()
+            .get_all_properties::<(String,)>()
+            .await
+            .unwrap_err();
+
+        Ok(())
+    }
+
+    #[test]
+    #[timeout(15000)]
+    fn connect_signal() {
+        block_on(test_connect_signal()).unwrap();
+    }
+
+    async fn test_connect_signal() -> Result<()> {
+        let conn = Connection::session().await?;
+        let unique_name = conn.unique_name().unwrap().clone();
+        let proxy = Proxy::new(&conn, &unique_name, "/org/zbus/Signal", "org.zbus.Signal").await?;
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let task = proxy
+            .connect_signal("Changed", move |msg| tx.send(msg).unwrap())
+            .await?;
+
+        for i in 0..2u32 {
+            conn.emit_signal(
+                Some(&unique_name),
+                "/org/zbus/Signal",
+                "org.zbus.Signal",
+                "Changed",
+                &i,
+            )
+            .await?;
+            let msg = rx.recv().await.unwrap();
+            assert_eq!(msg.body().deserialize::<u32>()?, i);
+        }
+
+        // Dropping the task disconnects the callback, dropping it along with the sender.
+        drop(task);
+        assert!(rx.recv().await.is_none());
+
+        Ok(())
+    }
 }
@@ -11,6 +11,86 @@ pub(crate) fn padding_for_n_bytes(value: usize, align: usize) -> usize {
     len_rounded_up.wrapping_sub(value)
 }
 
+/// The D-Bus type code of a single complete type, as a byte.
+///
+/// Returns `Some` with the ASCII type code (e.g `b's'`) if `signature` is the signature of a
+/// single-character type, i.e a basic type or a variant. Returns `None` for container types
+/// (arrays, dictionaries and structures), invalid signatures and signatures of more than one type.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(zbus::dbus_type_code("u"), Some(b'u'));
+/// assert_eq!(zbus::dbus_type_code("v"), Some(b'v'));
+/// assert_eq!(zbus::dbus_type_code("as"), None);
+/// assert_eq!(zbus::dbus_type_code("ss"), None);
+/// ```
+pub fn dbus_type_code(signature: &str) -> Option<u8> {
+    zvariant::Signature::try_from(signature)
+        .ok()
+        .filter(|s| s.string_len() == 1)
+        .map(|_| signature.as_bytes()[0])
+}
+
+/// The name of the D-Bus type of a single complete type.
+///
+/// Returns the name of the type from the specification, in camel case (e.g `"UInt32"` for `UINT32`,
+/// i.e `u`), if `signature` is the signature of a single-character type, i.e a basic type or a
+/// variant. As with [`dbus_type_code`], `None` is returned for container types, invalid signatures
+/// and signatures of more than one type.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(zbus::signature_to_type_name("y"), Some("Byte"));
+/// assert_eq!(zbus::signature_to_type_name("o"), Some("ObjectPath"));
+/// assert_eq!(zbus::signature_to_type_name("a{sv}"), None);
+/// ```
+pub fn signature_to_type_name(signature: &str) -> Option<&'static str> {
+    use zvariant::Signature;
+
+    let name = match Signature::try_from(signature).ok()? {
+        Signature::U8 => "Byte",
+        Signature::Bool => "Boolean",
+        Signature::I16 => "Int16",
+        Signature::U16 => "UInt16",
+        Signature::I32 => "Int32",
+        Signature::U32 => "UInt32",
+        Signature::I64 => "Int64",
+        Signature::U64 => "UInt64",
+        Signature::F64 => "Double",
+        Signature::Str => "String",
+        Signature::ObjectPath => "ObjectPath",
+        Signature::Signature => "Signature",
+        Signature::Variant => "Variant",
+        #[cfg(unix)]
+        Signature::Fd => "UnixFD",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+/// The numeric ID of a unique name, e.g `42` for `:1.42`.
+///
+/// Bus implementations typically assign unique names of the form `:<N>.<ID>`, where `ID` is
+/// incremented for each new connection, so it can be used to order connections by the time they
+/// connected. Note that this is a convention rather than a requirement of the specification.
+///
+/// Returns `None` if `name` is not a valid unique name or it doesn't end with a numeric ID.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(zbus::unique_name_to_id(":1.42"), Some(42));
+/// assert_eq!(zbus::unique_name_to_id("org.freedesktop.DBus"), None);
+/// ```
+pub fn unique_name_to_id(name: &str) -> Option<u64> {
+    zbus_names::UniqueName::try_from(name)
+        .ok()
+        .and_then(|name| name.rsplit_once('.')?.1.parse().ok())
+}
+
 /// Helper trait for macro-generated code.
 ///
 /// This trait allows macros to refer to the `Ok` and `Err` types of a [Result] that is behind a
@@ -53,3 +133,77 @@ pub fn block_on<F: std::future::Future>(future: F) -> F::Output {
 pub(crate) fn is_flatpak() -> bool {
     std::env::var("FLATPAK_ID").is_ok()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{dbus_type_code, signature_to_type_name, unique_name_to_id};
+
+    #[test]
+    fn type_code() {
+        for code in [
+            b'y', b'b', b'n', b'q', b'i', b'u', b'x', b't', b'd', b's', b'o', b'g', b'v',
+        ] {
+            let signature = [code];
+            let signature = std::str::from_utf8(&signature).unwrap();
+            assert_eq!(dbus_type_code(signature), Some(code));
+        }
+        #[cfg(unix)]
+        assert_eq!(dbus_type_code("h"), Some(b'h'));
+
+        // Container types don't have a single-character signature..
+        for signature in ["ay", "a{sv}", "(ii)", "(s)"] {
+            assert_eq!(dbus_type_code(signature), None);
+        }
+        // ..and their type codes alone are not valid signatures.
+        for signature in ["a", "r", "e", "(", ")", "{", "}"] {
+            assert_eq!(dbus_type_code(signature), None);
+        }
+        // Neither are empty signatures or multiple types.
+        assert_eq!(dbus_type_code(""), None);
+        assert_eq!(dbus_type_code("us"), None);
+    }
+
+    #[test]
+    fn type_name() {
+        // The same types as those with a type code.
+        for (signature, name) in [
+            ("y", "Byte"),
+            ("b", "Boolean"),
+            ("n", "Int16"),
+            ("q", "UInt16"),
+            ("i", "Int32"),
+            ("u", "UInt32"),
+            ("x", "Int64"),
+            ("t", "UInt64"),
+            ("d", "Double"),
+            ("s", "String"),
+            ("o", "ObjectPath"),
+            ("g", "Signature"),
+            ("v", "Variant"),
+        ] {
+            assert_eq!(signature_to_type_name(signature), Some(name));
+            assert_eq!(dbus_type_code(signature), Some(signature.as_bytes()[0]));
+        }
+        #[cfg(unix)]
+        assert_eq!(signature_to_type_name("h"), Some("UnixFD"));
+
+        for signature in ["", "ay", "a{sv}", "(ii)", "(s)", "us", "a", "z"] {
+            assert_eq!(signature_to_type_name(signature), None);
+        }
+    }
+
+    #[test]
+    fn unique_name_id() {
+        assert_eq!(unique_name_to_id(":1.42"), Some(42));
+        assert_eq!(unique_name_to_id(":1.1000"), Some(1000));
+        assert_eq!(unique_name_to_id(":1.18446744073709551615"), Some(u64::MAX));
+
+        // Not unique names.
+        assert_eq!(unique_name_to_id("org.foo"), None);
+        assert_eq!(unique_name_to_id("org.foo1.42"), None);
+        // Unique names not following the convention.
+        assert_eq!(unique_name_to_id(":1.abc"), None);
+        assert_eq!(unique_name_to_id(":1.18446744073709551616"), None);
+        assert_eq!(unique_name_to_id("org.freedesktop.DBus"), None);
+    }
+}
@@ -247,7 +247,7 @@ pub fn proxy(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///       signal.
 ///     * `"const"` - the property never changes, thus no signal is ever emitted for it.
 ///     * `"false"` - the change signal is not emitted if the property changes.
-
+///
 /// * `signal` - the method is a "signal". It must be a method declaration (without body). Its code
 ///   block will be expanded to emit the signal from the object path associated with the interface
 ///   instance.
@@ -1,6 +1,7 @@
 use core::{
     borrow::Borrow,
     fmt::{self, Debug, Display, Formatter},
+    hash::{Hash, Hasher},
     ops::Deref,
 };
 use std::{borrow::Cow, sync::Arc};
@@ -46,7 +47,7 @@ use zvariant::{NoneValue, OwnedValue, Str, Type, Value};
 /// ```
 ///
 /// [bus name]: https://dbus.freedesktop.org/doc/dbus-specification.html#message-protocol-names-bus
-#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 #[serde(untagged)]
 pub enum BusName<'name> {
     #[serde(borrow)]
@@ -117,6 +118,13 @@ impl Borrow<str> for BusName<'_> {
     }
 }
 
+// Hashed as a string, as required by the `Borrow<str>` implementation.
+impl Hash for BusName<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
 impl Debug for BusName<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
@@ -0,0 +1,173 @@
+use zbus_names::{OwnedInterfaceName, OwnedMemberName, OwnedPropertyName};
+
+use crate::{body_signature, Interface, Node};
+
+/// A difference between two versions of an interface, as reported by [`compare_interfaces`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InterfaceDiff {
+    /// A method was added to the interface.
+    MethodAdded {
+        interface: OwnedInterfaceName,
+        method: OwnedMemberName,
+    },
+    /// A method was removed from the interface.
+    MethodRemoved {
+        interface: OwnedInterfaceName,
+        method: OwnedMemberName,
+    },
+    /// The input or output signature of a method changed.
+    MethodSignatureChanged {
+        interface: OwnedInterfaceName,
+        method: OwnedMemberName,
+    },
+    /// A signal was added to the interface.
+    SignalAdded {
+        interface: OwnedInterfaceName,
+        signal: OwnedMemberName,
+    },
+    /// A signal was removed from the interface.
+    SignalRemoved {
+        interface: OwnedInterfaceName,
+        signal: OwnedMemberName,
+    },
+    /// The signature of a signal changed.
+    SignalSignatureChanged {
+        interface: OwnedInterfaceName,
+        signal: OwnedMemberName,
+    },
+    /// A property was added to the interface.
+    PropertyAdded {
+        interface: OwnedInterfaceName,
+        property: OwnedPropertyName,
+    },
+    /// A property was removed from the interface.
+    PropertyRemoved {
+        interface: OwnedInterfaceName,
+        property: OwnedPropertyName,
+    },
+    /// The type or the access of a property changed.
+    PropertyChanged {
+        interface: OwnedInterfaceName,
+        property: OwnedPropertyName,
+    },
+}
+
+/// Compare the interfaces of two versions of a node.
+///
+/// Only the interfaces directly on `old` and `new` are compared, not those of their children. An
+/// interface present on only one of the nodes is treated as having no members on the other, so all
+/// its methods, signals and properties are reported as added or removed.
+///
+/// Returns an empty `Vec` if there's no difference.
+pub fn compare_interfaces(old: &Node<'_>, new: &Node<'_>) -> Vec<InterfaceDiff> {
+    let mut diffs = vec![];
+    for old_iface in old.interfaces() {
+        let new_iface = find_interface(new, old_iface);
+        compare_interface(old_iface, Some(old_iface), new_iface, &mut diffs);
+    }
+    for new_iface in new.interfaces() {
+        if find_interface(old, new_iface).is_none() {
+            compare_interface(new_iface, None, Some(new_iface), &mut diffs);
+        }
+    }
+
+    diffs
+}
+
+fn find_interface<'n, 'a>(node: &'n Node<'a>, iface: &Interface<'_>) -> Option<&'n Interface<'a>> {
+    node.interfaces().iter().find(|i| i.name() == iface.name())
+}
+
+fn compare_interface(
+    iface: &Interface<'_>,
+    old: Option<&Interface<'_>>,
+    new: Option<&Interface<'_>>,
+    diffs: &mut Vec<InterfaceDiff>,
+) {
+    let interface = OwnedInterfaceName::from(iface.name());
+    let old_methods = old.map(|iface| iface.methods()).unwrap_or_default();
+    let new_methods = new.map(|iface| iface.methods()).unwrap_or_default();
+    let old_signals = old.map(|iface| iface.signals()).unwrap_or_default();
+    let new_signals = new.map(|iface| iface.signals()).unwrap_or_default();
+    let old_properties = old.map(|iface| iface.properties()).unwrap_or_default();
+    let new_properties = new.map(|iface| iface.properties()).unwrap_or_default();
+
+    for method in old_methods {
+        let diff = match new_methods.iter().find(|m| m.name() == method.name()) {
+            None => InterfaceDiff::MethodRemoved {
+                interface: interface.clone(),
+                method: method.name().into(),
+            },
+            Some(m)
+                if m.in_signature() != method.in_signature()
+                    || m.out_signature() != method.out_signature() =>
+            {
+                InterfaceDiff::MethodSignatureChanged {
+                    interface: interface.clone(),
+                    method: method.name().into(),
+                }
+            }
+            Some(_) => continue,
+        };
+        diffs.push(diff);
+    }
+    for method in new_methods {
+        if !old_methods.iter().any(|m| m.name() == method.name()) {
+            diffs.push(InterfaceDiff::MethodAdded {
+                interface: interface.clone(),
+                method: method.name().into(),
+            });
+        }
+    }
+
+    for signal in old_signals {
+        let diff = match new_signals.iter().find(|s| s.name() == signal.name()) {
+            None => InterfaceDiff::SignalRemoved {
+                interface: interface.clone(),
+                signal: signal.name().into(),
+            },
+            Some(s) if body_signature(s.args().iter()) != body_signature(signal.args().iter()) => {
+                InterfaceDiff::SignalSignatureChanged {
+                    interface: interface.clone(),
+                    signal: signal.name().into(),
+                }
+            }
+            Some(_) => continue,
+        };
+        diffs.push(diff);
+    }
+    for signal in new_signals {
+        if !old_signals.iter().any(|s| s.name() == signal.name()) {
+            diffs.push(InterfaceDiff::SignalAdded {
+                interface: interface.clone(),
+                signal: signal.name().into(),
+            });
+        }
+    }
+
+    for property in old_properties {
+        let diff = match new_properties.iter().find(|p| p.name() == property.name()) {
+            None => InterfaceDiff::PropertyRemoved {
+                interface: interface.clone(),
+                property: property.name().into(),
+            },
+            Some(p) if p.ty() != property.ty() || p.access() != property.access() => {
+                InterfaceDiff::PropertyChanged {
+                    interface: interface.clone(),
+                    property: property.name().into(),
+                }
+            }
+            Some(_) => continue,
+        };
+        diffs.push(diff);
+    }
+    for property in new_properties {
+        if !old_properties.iter().any(|p| p.name() == property.name()) {
+            diffs.push(InterfaceDiff::PropertyAdded {
+                interface: interface.clone(),
+                property: property.name().into(),
+            });
+        }
+    }
+}
@@ -14,6 +14,9 @@
 mod error;
 pub use error::{Error, Result};
 
+mod diff;
+pub use diff::{compare_interfaces, InterfaceDiff};
+
 use quick_xml::{de::Deserializer, se::to_writer};
 use serde::{Deserialize, Serialize};
 use static_assertions::assert_impl_all;
@@ -121,6 +124,39 @@ impl<'a> Method<'a> {
     pub fn annotations(&self) -> &[Annotation] {
         &self.annotations
     }
+
+    /// Return the signature of the method call body.
+    ///
+    /// This is the concatenation of the signatures of the input arguments, in the same form as
+    /// message body signatures: multiple arguments result in a structure signature and no argument
+    /// in [`zvariant::Signature::Unit`]. Arguments with no direction are input arguments.
+    pub fn in_signature(&self) -> zvariant::Signature {
+        body_signature(
+            self.args
+                .iter()
+                .filter(|arg| arg.direction != Some(ArgDirection::Out)),
+        )
+    }
+
+    /// Return the signature of the method reply body.
+    ///
+    /// Same as [`Method::in_signature`], but for the output arguments.
+    pub fn out_signature(&self) -> zvariant::Signature {
+        body_signature(
+            self.args
+                .iter()
+                .filter(|arg| arg.direction == Some(ArgDirection::Out)),
+        )
+    }
+}
+
+fn body_signature<'a>(args: impl Iterator<Item = &'a Arg>) -> zvariant::Signature {
+    let mut signatures: Vec<_> = args.map(|arg| arg.ty.inner().clone()).collect();
+    match signatures.len() {
+        0 => zvariant::Signature::Unit,
+        1 => signatures.remove(0),
+        _ => zvariant::Signature::structure(signatures),
+    }
 }
 
 /// A signal
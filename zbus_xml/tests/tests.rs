@@ -1,7 +1,7 @@
 use quick_xml::de::DeError;
 use std::error::Error;
 
-use zbus_xml::{ArgDirection, Node};
+use zbus_xml::{compare_interfaces, ArgDirection, InterfaceDiff, Node};
 
 #[test]
 fn serde() -> Result<(), Box<dyn Error>> {
@@ -36,3 +36,147 @@ fn invalid_arg_type() {
         Err(zbus_xml::Error::QuickXml(DeError::Custom(_)))
     ));
 }
+
+#[test]
+fn method_signatures() -> Result<(), Box<dyn Error>> {
+    let example = include_str!("data/sample_object0.xml");
+    let node = Node::try_from(example)?;
+    let methods = node.interfaces()[0].methods();
+
+    let frobate = &methods[0];
+    assert_eq!(frobate.in_signature().to_string_no_parens(), "i");
+    assert_eq!(frobate.out_signature().to_string_no_parens(), "sa{us}");
+    assert!(matches!(
+        frobate.out_signature(),
+        zvariant::Signature::Structure(_)
+    ));
+
+    let mogrify = &methods[2];
+    assert_eq!(mogrify.in_signature().to_string(), "(iiav)");
+    assert_eq!(mogrify.out_signature(), zvariant::Signature::Unit);
+
+    Ok(())
+}
+
+#[test]
+fn interface_diff() -> Result<(), Box<dyn Error>> {
+    let old = Node::try_from(
+        r#"
+        <node>
+          <interface name="org.example.Files">
+            <method name="Open">
+              <arg name="path" type="s" direction="in"/>
+              <arg name="fd" type="h" direction="out"/>
+            </method>
+            <method name="Close">
+              <arg name="fd" type="h" direction="in"/>
+            </method>
+            <signal name="Opened">
+              <arg name="path" type="s"/>
+            </signal>
+            <signal name="Closed"/>
+            <property name="Count" type="u" access="read"/>
+            <property name="Root" type="s" access="read"/>
+            <property name="Label" type="s" access="read"/>
+          </interface>
+        </node>
+        "#,
+    )?;
+    let new = Node::try_from(
+        r#"
+        <node>
+          <interface name="org.example.Files">
+            <method name="Open">
+              <arg name="path" type="s" direction="in"/>
+              <arg name="fd" type="h" direction="out"/>
+            </method>
+            <method name="Close">
+              <arg name="fd" type="h" direction="in"/>
+            </method>
+            <method name="Sync"/>
+            <signal name="Opened">
+              <arg name="path" type="s"/>
+            </signal>
+            <signal name="Closed"/>
+            <property name="Count" type="u" access="read"/>
+            <property name="Root" type="s" access="read"/>
+            <property name="Label" type="s" access="read"/>
+          </interface>
+        </node>
+        "#,
+    )?;
+
+    assert!(compare_interfaces(&old, &old).is_empty());
+    assert_eq!(
+        compare_interfaces(&old, &new),
+        [InterfaceDiff::MethodAdded {
+            interface: "org.example.Files".try_into()?,
+            method: "Sync".try_into()?,
+        }]
+    );
+    assert_eq!(
+        compare_interfaces(&new, &old),
+        [InterfaceDiff::MethodRemoved {
+            interface: "org.example.Files".try_into()?,
+            method: "Sync".try_into()?,
+        }]
+    );
+
+    let changed = Node::try_from(
+        r#"
+        <node>
+          <interface name="org.example.Files">
+            <method name="Open">
+              <arg name="path" type="ay" direction="in"/>
+              <arg name="fd" type="h" direction="out"/>
+            </method>
+            <method name="Close">
+              <arg name="fd" type="h" direction="in"/>
+            </method>
+            <signal name="Opened">
+              <arg name="path" type="ay"/>
+            </signal>
+            <signal name="Synced"/>
+            <property name="Count" type="t" access="read"/>
+            <property name="Root" type="s" access="readwrite"/>
+          </interface>
+        </node>
+        "#,
+    )?;
+    let interface: zbus_names::OwnedInterfaceName = "org.example.Files".try_into()?;
+    assert_eq!(
+        compare_interfaces(&old, &changed),
+        [
+            InterfaceDiff::MethodSignatureChanged {
+                interface: interface.clone(),
+                method: "Open".try_into()?,
+            },
+            InterfaceDiff::SignalSignatureChanged {
+                interface: interface.clone(),
+                signal: "Opened".try_into()?,
+            },
+            InterfaceDiff::SignalRemoved {
+                interface: interface.clone(),
+                signal: "Closed".try_into()?,
+            },
+            InterfaceDiff::SignalAdded {
+                interface: interface.clone(),
+                signal: "Synced".try_into()?,
+            },
+            InterfaceDiff::PropertyChanged {
+                interface: interface.clone(),
+                property: "Count".try_into()?,
+            },
+            InterfaceDiff::PropertyChanged {
+                interface: interface.clone(),
+                property: "Root".try_into()?,
+            },
+            InterfaceDiff::PropertyRemoved {
+                interface,
+                property: "Label".try_into()?,
+            },
+        ]
+    );
+
+    Ok(())
+}
@@ -67,6 +67,57 @@ impl<'a> Array<'a> {
         Ok(())
     }
 
+    /// Append all the elements of `other`, returning the combined `Array`.
+    ///
+    /// # Errors
+    ///
+    /// if the element signature of `other` doesn't match that of `self`.
+    pub fn concat(mut self, other: Array<'a>) -> Result<Array<'a>> {
+        if other.signature != self.signature {
+            return Err(Error::SignatureMismatch(
+                other.element_signature().clone(),
+                self.element_signature().to_string(),
+            ));
+        }
+        self.elements.extend(other.elements);
+
+        Ok(self)
+    }
+
+    /// Split the `Array` into two at the given index.
+    ///
+    /// The first returned `Array` contains the elements in `[0, index)` and the second one, the
+    /// elements in `[index, len)`. Both have the same signature as `self`.
+    ///
+    /// # Panics
+    ///
+    /// if `index > len`.
+    pub fn split_at(mut self, index: usize) -> (Array<'a>, Array<'a>) {
+        let tail = Array {
+            elements: self.elements.split_off(index),
+            signature: self.signature.clone(),
+        };
+
+        (self, tail)
+    }
+
+    /// Retain only the elements for which `f` returns `true`.
+    ///
+    /// This works like [`Vec::retain`].
+    pub fn retain<F>(&mut self, f: F)
+    where
+        F: FnMut(&Value<'a>) -> bool,
+    {
+        self.elements.retain(f);
+    }
+
+    /// Remove consecutive repeated elements.
+    ///
+    /// This works like [`Vec::dedup`].
+    pub fn dedup(&mut self) {
+        self.elements.dedup();
+    }
+
     /// Get all the elements.
     pub fn inner(&self) -> &[Value<'a>] {
         &self.elements
@@ -82,7 +133,6 @@ impl<'a> Array<'a> {
             .get(idx)
             .map(|v| v.downcast_ref::<V>())
             .transpose()
-            .map_err(Into::into)
     }
 
     /// Get the number of elements.
@@ -1,4 +1,7 @@
-use crate::{Error, MaxDepthExceeded, Result};
+use crate::{
+    signature::{MAX_ARRAY_DEPTH, MAX_STRUCT_DEPTH, MAX_TOTAL_DEPTH},
+    Error, MaxDepthExceeded, Result,
+};
 
 // We take the limits from the D-Bus specification for gvariant as well.
 //
@@ -7,9 +10,6 @@ use crate::{Error, MaxDepthExceeded, Result};
 // limits and expand if/when needed.
 //
 // [mistake]: https://gitlab.gnome.org/GNOME/glib/-/commit/7c4e6e9fbe473de0401c778c6b0c4aad27d5145a
-const MAX_STRUCT_DEPTH: u8 = 32;
-const MAX_ARRAY_DEPTH: u8 = 32;
-const MAX_TOTAL_DEPTH: u8 = 64;
 
 // Represents the current depth of all container being (de)serialized.
 #[derive(Debug, Default, Clone, Copy)]
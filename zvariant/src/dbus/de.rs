@@ -392,6 +392,9 @@ impl<'d, 'de, 'sig, 'f, #[cfg(unix)] F: AsFd, #[cfg(not(unix))] F>
         de.0.container_depths = de.0.container_depths.inc_array()?;
 
         let len = de.0.ctxt.endian().read_u32(de.0.next_slice(4)?) as usize;
+        if len > MAX_ARRAY_LEN {
+            return Err(Error::ArrayTooLarge(len));
+        }
 
         // D-Bus expects us to add padding for the first element even when there is no first
         // element (i-e empty array) so we parse padding already.
@@ -160,9 +160,12 @@ where
     }
 
     fn serialize_bytes(self, v: &[u8]) -> Result<()> {
+        if v.len() > MAX_ARRAY_LEN {
+            return Err(Error::ArrayTooLarge(v.len()));
+        }
         self.0.add_padding(ARRAY_ALIGNMENT_DBUS)?;
         self.0
-            .write_u32(self.0.ctxt.endian(), v.len() as u32)
+            .write_u32(self.0.ctxt.endian(), usize_to_u32(v.len()))
             .map_err(|e| Error::InputOutput(e.into()))?;
         self.0
             .write(v)
@@ -376,6 +379,9 @@ where
     pub(self) fn end_seq(self) -> Result<()> {
         // Set size of array in bytes
         let array_len = self.ser.0.bytes_written - self.start;
+        if array_len > MAX_ARRAY_LEN {
+            return Err(Error::ArrayTooLarge(array_len));
+        }
         let len = usize_to_u32(array_len);
         let total_array_len = (array_len + self.first_padding + 4) as i64;
         self.ser
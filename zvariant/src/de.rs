@@ -86,15 +86,18 @@ impl<'de, 'sig, 'f, F> DeserializerCommon<'de, 'sig, 'f, F> {
     }
 
     pub fn next_slice(&mut self, len: usize) -> Result<&'de [u8]> {
-        if self.pos + len > self.bytes.len() {
-            return Err(serde::de::Error::invalid_length(
-                self.bytes.len(),
-                &format!(">= {}", self.pos + len).as_str(),
-            ));
-        }
+        let end = match self.pos.checked_add(len) {
+            Some(end) if end <= self.bytes.len() => end,
+            _ => {
+                return Err(serde::de::Error::invalid_length(
+                    self.bytes.len(),
+                    &format!(">= {} + {}", self.pos, len).as_str(),
+                ))
+            }
+        };
 
-        let slice = &self.bytes[self.pos..self.pos + len];
-        self.pos += len;
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
 
         Ok(slice)
     }
@@ -97,6 +97,26 @@ impl<'k, 'v> Dict<'k, 'v> {
         self.map.get(&key).map(|v| v.downcast_ref()).transpose()
     }
 
+    /// Merge `other` into `self`, returning the combined `Dict`.
+    ///
+    /// The result contains all the entries of both dictionaries. If a key is present in both,
+    /// the value from `other` takes precedence.
+    ///
+    /// # Errors
+    ///
+    /// if the signature of `other` doesn't match that of `self`.
+    pub fn merge(mut self, other: Dict<'k, 'v>) -> Result<Self, Error> {
+        if other.signature != self.signature {
+            return Err(Error::SignatureMismatch(
+                other.signature,
+                self.signature.to_string(),
+            ));
+        }
+        self.map.extend(other.map);
+
+        Ok(self)
+    }
+
     /// Get the signature of this `Dict`.
     pub fn signature(&self) -> &Signature {
         &self.signature
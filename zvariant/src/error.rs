@@ -66,6 +66,10 @@ pub enum Error {
     SignatureParse(crate::signature::Error),
     /// Attempted to create an empty structure (which is not allowed by the D-Bus specification).
     EmptyStructure,
+    /// The length of an array in bytes (the argument) exceeds [`MAX_ARRAY_LEN`].
+    ///
+    /// [`MAX_ARRAY_LEN`]: crate::MAX_ARRAY_LEN
+    ArrayTooLarge(usize),
 }
 
 assert_impl_all!(Error: Send, Sync, Unpin);
@@ -81,6 +85,7 @@ impl PartialEq for Error {
             (Error::UnknownFd, Error::UnknownFd) => true,
             (Error::MaxDepthExceeded(max1), Error::MaxDepthExceeded(max2)) => max1 == max2,
             (Error::MissingFramingOffset, Error::MissingFramingOffset) => true,
+            (Error::ArrayTooLarge(len1), Error::ArrayTooLarge(len2)) => len1 == len2,
             (_, _) => false,
         }
     }
@@ -124,6 +129,9 @@ impl fmt::Display for Error {
             Error::MaxDepthExceeded(max) => write!(f, "{max}"),
             Error::SignatureParse(e) => write!(f, "{e}"),
             Error::EmptyStructure => write!(f, "Attempted to create an empty structure"),
+            Error::ArrayTooLarge(len) => {
+                write!(f, "Array of {len} bytes exceeds the maximum of 2^26 bytes")
+            }
         }
     }
 }
@@ -148,6 +156,7 @@ impl Clone for Error {
             Error::MaxDepthExceeded(max) => Error::MaxDepthExceeded(*max),
             Error::SignatureParse(e) => Error::SignatureParse(*e),
             Error::EmptyStructure => Error::EmptyStructure,
+            Error::ArrayTooLarge(len) => Error::ArrayTooLarge(*len),
         }
     }
 }
@@ -1,5 +1,13 @@
-use std::{borrow::Cow, path::{Path, PathBuf}};
-use serde::{de::{self, Visitor}, Deserialize, Deserializer, Serialize, Serializer};
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::{
+    borrow::Cow,
+    ffi::OsStr,
+    path::{Path, PathBuf},
+    str::Utf8Error,
+};
 
 use crate::Type;
 
@@ -24,10 +32,77 @@ use crate::Type;
 /// assert_eq!(p1, p2);
 /// assert_eq!(p2, p3);
 /// ```
-#[derive(Type, Debug, Default, PartialEq, Eq)]
+///
+/// The bytes of the path are preserved exactly, both ways: no normalization is applied. In the
+/// same spirit, two `FilePath`s are only equal if their byte representations are, so unlike
+/// [`Path`], `a/b/` and `a/b` are considered different.
+#[derive(Type, Debug, Default)]
 #[zvariant(signature = "ay")]
 pub struct FilePath<'f>(Cow<'f, Path>);
 
+impl<'f> FilePath<'f> {
+    /// Create a `FilePath` from the raw bytes of a path, without copying them.
+    ///
+    /// On Unix, paths are arbitrary bytes so this always succeeds and preserves the bytes
+    /// exactly, even if they're not valid UTF-8. On other platforms, the bytes must be valid UTF-8.
+    pub fn try_from_bytes(bytes: &'f [u8]) -> Result<Self, Utf8Error> {
+        os_str_from_bytes(bytes).map(|s| Self::from(Path::new(s)))
+    }
+
+    /// The path as an [`OsStr`].
+    pub fn as_os_str(&self) -> &OsStr {
+        self.0.as_os_str()
+    }
+
+    /// The path as a [`Path`].
+    pub fn as_path(&self) -> &Path {
+        &self.0
+    }
+
+    /// The final component of the path, if there is one.
+    ///
+    /// See [`Path::file_name`] for details.
+    pub fn file_name(&self) -> Option<&OsStr> {
+        self.0.file_name()
+    }
+
+    /// The extension of the final component of the path, if there is one.
+    ///
+    /// See [`Path::extension`] for details.
+    pub fn extension(&self) -> Option<&OsStr> {
+        self.0.extension()
+    }
+
+    /// The path without its final component, if there is one.
+    ///
+    /// See [`Path::parent`] for details.
+    pub fn parent(&self) -> Option<FilePath<'_>> {
+        self.0.parent().map(FilePath::from)
+    }
+
+    /// Create an owned `FilePath` with `component` appended to this path.
+    ///
+    /// See [`Path::join`] for details.
+    pub fn join(&self, component: impl AsRef<Path>) -> FilePath<'static> {
+        FilePath(Cow::Owned(self.0.join(component)))
+    }
+
+    /// The canonical, absolute form of the path, with all intermediate components normalized and
+    /// symbolic links resolved.
+    ///
+    /// See [`Path::canonicalize`] for details.
+    pub fn canonicalize(&self) -> std::io::Result<FilePath<'static>> {
+        self.0.canonicalize().map(|p| FilePath(Cow::Owned(p)))
+    }
+}
+
+impl PartialEq for FilePath<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_os_str() == other.0.as_os_str()
+    }
+}
+
+impl Eq for FilePath<'_> {}
 
 impl<'f> From<&'f Path> for FilePath<'f> {
     fn from(value: &'f Path) -> Self {
@@ -50,7 +125,7 @@ impl<'f> From<&'f str> for FilePath<'f> {
 impl<'de> Deserialize<'de> for FilePath<'de> {
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
-        D: Deserializer<'de>
+        D: Deserializer<'de>,
     {
         struct FilePathVisitor;
         impl<'de> Visitor<'de> for FilePathVisitor {
@@ -60,18 +135,20 @@ impl<'de> Deserialize<'de> for FilePath<'de> {
                 formatter.write_str("a byte array")
             }
 
+            fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> std::result::Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                FilePath::try_from_bytes(v).map_err(|_| invalid_bytes(v))
+            }
+
             fn visit_bytes<E>(self, v: &[u8]) -> std::result::Result<Self::Value, E>
             where
                 E: de::Error,
             {
-                Ok(FilePath::from(PathBuf::from(
-                            // SAFETY: File path do not necessarily contain only a sequence
-                            // of UTF-8 characters, thus it's safe to assume that the path *contains*
-                            // a non-vaalid UTF-8 characters, hence the `unsafe` block.
-                            unsafe{
-                                String::from_utf8_unchecked(v.to_vec())
-                            }
-                )))
+                os_str_from_bytes(v)
+                    .map(|s| FilePath::from(PathBuf::from(s)))
+                    .map_err(|_| invalid_bytes(v))
             }
         }
         let visitor = FilePathVisitor;
@@ -79,33 +156,49 @@ impl<'de> Deserialize<'de> for FilePath<'de> {
     }
 }
 
+fn invalid_bytes<E: de::Error>(bytes: &[u8]) -> E {
+    E::invalid_value(de::Unexpected::Bytes(bytes), &"a UTF-8 file path")
+}
+
+#[cfg(unix)]
+fn os_str_from_bytes(bytes: &[u8]) -> Result<&OsStr, Utf8Error> {
+    use std::os::unix::ffi::OsStrExt;
+
+    Ok(OsStr::from_bytes(bytes))
+}
+
+// Outside of Unix, paths are not arbitrary bytes so we can only accept valid UTF-8.
+#[cfg(not(unix))]
+fn os_str_from_bytes(bytes: &[u8]) -> Result<&OsStr, Utf8Error> {
+    std::str::from_utf8(bytes).map(OsStr::new)
+}
+
 impl<'f> Serialize for FilePath<'f> {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
-        S: Serializer
+        S: Serializer,
     {
-        serializer.serialize_bytes(&self.0.as_os_str().as_encoded_bytes())
+        serializer.serialize_bytes(self.0.as_os_str().as_encoded_bytes())
     }
 }
 
-
 impl<'f> AsRef<FilePath<'f>> for FilePath<'f> {
-    fn as_ref(& self) -> &FilePath<'f> {
-        &self
+    fn as_ref(&self) -> &FilePath<'f> {
+        self
     }
 }
 
-impl<'f> Into<PathBuf> for FilePath<'f> {
-    fn into(self) -> PathBuf {
-        PathBuf::from(self.0.into_owned())
+impl<'f> From<FilePath<'f>> for PathBuf {
+    fn from(value: FilePath<'f>) -> Self {
+        value.0.into_owned()
     }
 }
 
 #[cfg(test)]
-mod file_path {
+mod tests {
+    use super::*;
     use crate::zvariant::Signature;
     use std::path::{Path, PathBuf};
-    use super::*;
 
     #[test]
     fn filepath_from() {
@@ -128,6 +221,105 @@ mod file_path {
         );
     }
 
+    #[test]
+    fn byte_equality() {
+        assert_ne!(FilePath::from("a/b/"), FilePath::from("a/b"));
+        assert_ne!(FilePath::from("./a"), FilePath::from("a"));
+        assert_eq!(FilePath::from(""), FilePath::default());
+    }
+
+    #[test]
+    fn round_trip() {
+        use crate::{serialized::Context, to_bytes, LE};
+
+        let ctxt = Context::new_dbus(LE, 0);
+        for path in ["", "./a/../b", "a/b/", "/hello//world/"] {
+            let path = FilePath::from(path);
+            let encoded = to_bytes(ctxt, &path).unwrap();
+            let decoded: FilePath<'_> = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded, path);
+        }
+
+        let encoded = to_bytes(ctxt, &FilePath::default()).unwrap();
+        assert_eq!(encoded.len(), 4);
+        let decoded: FilePath<'_> = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, FilePath::default());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_utf8_round_trip() {
+        use crate::{serialized::Context, to_bytes, LE};
+        use std::os::unix::ffi::OsStrExt;
+
+        let ctxt = Context::new_dbus(LE, 0);
+        let path = FilePath::from(Path::new(OsStr::from_bytes(b"/tmp/\xff\xfe")));
+        let encoded = to_bytes(ctxt, &path).unwrap();
+        let decoded: FilePath<'_> = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, path);
+    }
+
+    #[test]
+    fn components() {
+        let path = FilePath::from("/tmp/dir/foo.txt");
+        assert_eq!(path.as_path(), Path::new("/tmp/dir/foo.txt"));
+        assert_eq!(path.file_name(), Some(OsStr::new("foo.txt")));
+        assert_eq!(path.extension(), Some(OsStr::new("txt")));
+        assert_eq!(path.parent(), Some(FilePath::from("/tmp/dir")));
+
+        assert_eq!(
+            FilePath::from("/tmp").join("foo.txt").as_path(),
+            Path::new("/tmp/foo.txt")
+        );
+
+        let root = FilePath::from("/");
+        assert_eq!(root.file_name(), None);
+        assert_eq!(root.extension(), None);
+        assert_eq!(root.parent(), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn canonicalize() {
+        let dir = std::env::temp_dir()
+            .canonicalize()
+            .unwrap()
+            .join(format!("zvariant-file-path-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let target = dir.join("target");
+        let link = dir.join("link");
+        std::fs::write(&target, b"").unwrap();
+        std::os::unix::fs::symlink(&target, &link).unwrap();
+
+        let path = FilePath::from(dir.join(".").join("link"));
+        let canonical = path.canonicalize();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(canonical.unwrap(), FilePath::from(target));
+
+        FilePath::from("/nonexistent/zvariant/path")
+            .canonicalize()
+            .unwrap_err();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn from_bytes() {
+        use std::os::unix::ffi::OsStrExt;
+
+        let bytes = b"/tmp/\xff\xfe";
+        let path = FilePath::try_from_bytes(bytes).unwrap();
+        assert_eq!(path.as_os_str().as_bytes(), bytes);
+        assert_eq!(path, FilePath::from(Path::new(OsStr::from_bytes(bytes))));
+    }
+
+    #[cfg(not(unix))]
+    #[test]
+    fn from_bytes() {
+        let path = FilePath::try_from_bytes(b"/tmp/foo").unwrap();
+        assert_eq!(path, FilePath::from("/tmp/foo"));
+        FilePath::try_from_bytes(b"/tmp/\xff\xfe").unwrap_err();
+    }
+
     #[test]
     fn into_test() {
         let first = PathBuf::from("/hello/world");
@@ -132,6 +132,29 @@ impl TryFrom<&Value<'_>> for String {
     }
 }
 
+impl TryFrom<&Value<'_>> for char {
+    type Error = Error;
+
+    fn try_from(value: &Value<'_>) -> Result<Self, Self::Error> {
+        let s = <&str>::try_from(value)?;
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(Error::Message(format!(
+                "expected a single character, got `{s}`"
+            ))),
+        }
+    }
+}
+
+impl TryFrom<Value<'_>> for char {
+    type Error = Error;
+
+    fn try_from(value: Value<'_>) -> Result<Self, Self::Error> {
+        char::try_from(&value)
+    }
+}
+
 impl<'a, T> TryFrom<Value<'a>> for Vec<T>
 where
     T: TryFrom<Value<'a>>,
@@ -148,6 +171,22 @@ where
     }
 }
 
+impl<'a, T> TryFrom<&'a Value<'a>> for Vec<T>
+where
+    T: TryFrom<&'a Value<'a>>,
+    T::Error: Into<crate::Error>,
+{
+    type Error = Error;
+
+    fn try_from(value: &'a Value<'a>) -> Result<Self, Self::Error> {
+        if let Value::Array(v) = value {
+            v.inner().iter().map(|e| e.downcast_ref()).collect()
+        } else {
+            Err(Error::IncorrectType)
+        }
+    }
+}
+
 impl TryFrom<Value<'_>> for OwnedObjectPath {
     type Error = Error;
 
@@ -215,6 +254,5 @@ where
 // impl<'a, T> TryFrom<Value<'a>> for Option<T>
 //
 // TODO: this could be useful
-// impl<'a, 'b, T> TryFrom<&'a Value<'b>> for Vec<T>
 // impl<'a, 'b, K, V, H> TryFrom<&'a Value<'v>> for HashMap<K, V, H>
 // and more..
@@ -53,6 +53,20 @@ into_value_from_both!(&'a str, Str);
 into_value_from_both!(Str<'a>, Str);
 into_value_from_both!(ObjectPath<'a>, ObjectPath);
 
+// There is no character type in D-Bus so just like in the serializers, a `char` is represented as
+// a string.
+impl<'a> From<char> for Value<'a> {
+    fn from(v: char) -> Self {
+        Value::Str(v.to_string().into())
+    }
+}
+
+impl<'a> From<&char> for Value<'a> {
+    fn from(v: &char) -> Self {
+        Value::from(*v)
+    }
+}
+
 macro_rules! try_into_value_from_ref {
     ($from:ty, $kind:ident) => {
         impl<'a> TryFrom<&'a $from> for Value<'a> {
@@ -519,6 +519,20 @@ mod tests {
         assert_eq!(encoded.len(), 10);
         let (v, _) = encoded.deserialize::<Value<'_>>().unwrap();
         assert_eq!(v, Value::new("c"));
+        assert_eq!(char::try_from(&v).unwrap(), 'c');
+
+        // Any code point can be carried, not just the ones fitting in a byte.
+        let v = Value::from('ü');
+        assert_eq!(v.value_signature(), "s");
+        assert_eq!(char::try_from(v).unwrap(), 'ü');
+        let v = Value::from('\u{1F980}');
+        assert_eq!(char::try_from(&v).unwrap(), '\u{1F980}');
+        assert!(char::try_from(Value::new("cc")).is_err());
+        assert!(char::try_from(Value::new("")).is_err());
+        assert_eq!(
+            char::try_from(Value::new(1u8)).unwrap_err(),
+            Error::IncorrectType
+        );
     }
 
     #[cfg(feature = "arrayvec")]
@@ -632,6 +646,110 @@ mod tests {
             .0;
     }
 
+    #[test]
+    fn array_concat_split() {
+        let head = Array::from(vec![1u32, 2]);
+        let tail = Array::from(vec![3u32, 4, 5]);
+        let array = head.concat(tail).unwrap();
+        assert_eq!(array.signature(), "au");
+        assert_eq!(
+            Vec::<u32>::try_from(array.try_clone().unwrap()).unwrap(),
+            [1, 2, 3, 4, 5]
+        );
+
+        let (head, tail) = array.split_at(2);
+        assert_eq!(head.signature(), "au");
+        assert_eq!(tail.signature(), "au");
+        assert_eq!(Vec::<u32>::try_from(head).unwrap(), [1, 2]);
+        assert_eq!(Vec::<u32>::try_from(tail).unwrap(), [3, 4, 5]);
+
+        let (head, tail) = Array::from(vec![1u32]).split_at(1);
+        assert_eq!(head.len(), 1);
+        assert!(tail.is_empty());
+
+        let strings = Array::from(vec!["a"]);
+        assert!(matches!(
+            Array::from(vec![1u32]).concat(strings).unwrap_err(),
+            Error::SignatureMismatch(..)
+        ));
+    }
+
+    #[test]
+    fn array_retain_dedup() {
+        let mut array = Array::from(vec![1u32, 2, 3, 4, 5, 6]);
+        array.retain(|v| matches!(v, Value::U32(n) if n % 2 == 0));
+        assert_eq!(array.signature(), "au");
+        assert_eq!(Vec::<u32>::try_from(array).unwrap(), [2, 4, 6]);
+
+        let mut array = Array::from(vec!["a", "a", "b", "a", "c", "c"]);
+        array.dedup();
+        assert_eq!(
+            Vec::<String>::try_from(array).unwrap(),
+            ["a", "b", "a", "c"]
+        );
+    }
+
+    #[test]
+    fn hash_set() {
+        use std::collections::HashSet;
+
+        assert_eq!(HashSet::<String>::SIGNATURE, "as");
+
+        let ctxt = Context::new_dbus(LE, 0);
+        let set: HashSet<String> = ["Hello", "World"].into_iter().map(String::from).collect();
+        let encoded = to_bytes(ctxt, &set).unwrap();
+        let decoded: HashSet<String> = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, set);
+        assert!(decoded.contains("World"));
+
+        // Duplicates are dropped.
+        let encoded = to_bytes(ctxt, &vec!["Hello", "World", "Hello"]).unwrap();
+        let decoded: HashSet<&str> = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded.len(), 2);
+        assert!(decoded.contains("Hello"));
+    }
+
+    #[test]
+    fn vec_value() {
+        fn roundtrip<T>(vec: Vec<T>, signature: &str)
+        where
+            T: Type + Clone + PartialEq + std::fmt::Debug + Into<Value<'static>>,
+            Vec<T>: for<'v> TryFrom<Value<'v>, Error = Error>,
+        {
+            let v = Value::from(vec.clone());
+            assert_eq!(v.value_signature(), signature);
+            assert_eq!(Vec::<T>::try_from(v.try_clone().unwrap()).unwrap(), vec);
+
+            let ctxt = Context::new_dbus(LE, 0);
+            let encoded = to_bytes(ctxt, &v).unwrap();
+            let decoded: Value<'_> = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded.value_signature(), signature);
+            assert_eq!(Vec::<T>::try_from(decoded).unwrap(), vec);
+        }
+
+        roundtrip(vec![String::from("Hello"), String::from("World")], "as");
+        roundtrip(vec![0u32, 1, u32::MAX], "au");
+        roundtrip(vec![0u64, 1, u64::MAX], "at");
+        roundtrip(vec![true, false], "ab");
+        roundtrip(Vec::<String>::new(), "as");
+
+        // Borrowing the elements.
+        let v = Value::from(vec!["Hello", "World"]);
+        let strs: Vec<&str> = v.downcast_ref().unwrap();
+        assert_eq!(strs, ["Hello", "World"]);
+        let Value::Array(array) = &v else { panic!() };
+        let Value::Str(first) = &array.inner()[0] else {
+            panic!()
+        };
+        assert!(std::ptr::eq(strs[0], first.as_str()));
+        let nums: Vec<u32> = Value::from(vec![1u32, 2]).downcast_ref().unwrap();
+        assert_eq!(nums, [1, 2]);
+        assert!(matches!(
+            v.downcast_ref::<Vec<u32>>().unwrap_err(),
+            Error::IncorrectType
+        ));
+    }
+
     #[test]
     fn array_value() {
         // Let's use D-Bus/GVariant terms
@@ -1056,6 +1174,25 @@ mod tests {
         assert_eq!(inner.0, 1);
         assert_eq!(inner.1, 2);
 
+        // The signature is computed from the fields.
+        let s = crate::StructureBuilder::new()
+            .add_field(1u32)
+            .add_field("hi")
+            .add_field(true)
+            .build()
+            .unwrap();
+        assert_eq!(s.signature(), "(usb)");
+        assert_eq!(s.get::<u32>(0).unwrap(), Some(1));
+        assert_eq!(s.get::<&str>(1).unwrap(), Some("hi"));
+        assert_eq!(s.get::<bool>(3).unwrap(), None);
+        assert!(matches!(s.get::<u32>(2), Err(Error::IncorrectType)));
+        let s = crate::StructureBuilder::new()
+            .add_field(s)
+            .add_field(Value::new(42u8))
+            .build()
+            .unwrap();
+        assert_eq!(s.signature(), "((usb)v)");
+
         #[derive(Serialize, Deserialize, Type, PartialEq, Debug)]
         struct Foo {
             val: u32,
@@ -1158,7 +1295,7 @@ mod tests {
         assert_eq!(map[&2], "456");
         // Use iterator
         let mut dict = Dict::from(map);
-        let expect = vec![
+        let expect = [
             (Value::from(1i64), Value::from("123")),
             (Value::from(2i64), Value::from("456")),
         ];
@@ -1221,6 +1358,14 @@ mod tests {
         }
         let ctxt = Context::new_dbus(LE, 0);
 
+        // The signature of an empty Dict comes from the key and value types.
+        let dict = Dict::new(<&str>::SIGNATURE, Value::SIGNATURE);
+        assert_eq!(dict.signature(), "a{sv}");
+        let dict = Dict::from(HashMap::<String, Value<'_>>::new());
+        assert_eq!(dict.signature(), "a{sv}");
+        let dict = Dict::from(BTreeMap::<u32, Vec<ObjectPath<'_>>>::new());
+        assert_eq!(dict.signature(), "a{uao}");
+
         // Now a hand-crafted Dict Value but with a Value as value
         let mut dict = Dict::new(<&str>::SIGNATURE, Value::SIGNATURE);
         dict.add("hello", Value::new("there")).unwrap();
@@ -1312,6 +1457,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn dict_padding() {
+        // Each dict entry is aligned to 8 bytes, like structures.
+        let mut map = BTreeMap::new();
+        map.insert("a", 1u32);
+        map.insert("bc", 2u32);
+        let ctxt = Context::new_dbus(LE, 0);
+        let expected: &[u8] = &[
+            28, 0, 0, 0, // array length, excluding the padding that follows it
+            0, 0, 0, 0, // padding to the first entry
+            1, 0, 0, 0, b'a', 0, // "a"
+            0, 0, // padding to the u32
+            1, 0, 0, 0, // 1
+            0, 0, 0, 0, // padding to the second entry
+            2, 0, 0, 0, b'b', b'c', 0, // "bc"
+            0, // padding to the u32
+            2, 0, 0, 0, // 2
+        ];
+        let encoded = to_bytes(ctxt, &map).unwrap();
+        assert_eq!(&*encoded, expected);
+        let decoded: BTreeMap<&str, u32> = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, map);
+
+        // Same for `Dict`, after the variant's signature and the padding to the array.
+        let v = Value::from(Dict::from(map.clone()));
+        let encoded = to_bytes(ctxt, &v).unwrap();
+        assert_eq!(&encoded[..8], b"\x05a{su}\0\0");
+        assert_eq!(&encoded[8..], expected);
+
+        let decoded: Value<'_> = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, v);
+    }
+
     #[test]
     fn dict_compare() {
         // the order in which a dict has been constructed must not play a role
@@ -1327,6 +1505,31 @@ mod tests {
         assert_eq!(dict1, dict2);
     }
 
+    #[test]
+    fn dict_merge() {
+        let mut defaults = Dict::new(<&str>::SIGNATURE, Value::SIGNATURE);
+        defaults.add("volume", Value::new(50u8)).unwrap();
+        defaults.add("muted", Value::new(false)).unwrap();
+
+        let mut overrides = Dict::new(<&str>::SIGNATURE, Value::SIGNATURE);
+        overrides.add("volume", Value::new(80u8)).unwrap();
+        overrides.add("device", Value::new("hdmi")).unwrap();
+
+        let merged = defaults.merge(overrides).unwrap();
+        assert_eq!(merged.iter().count(), 3);
+        assert_eq!(merged.get::<_, u8>(&"volume").unwrap(), Some(80));
+        assert_eq!(merged.get::<_, bool>(&"muted").unwrap(), Some(false));
+        assert_eq!(merged.get::<_, &str>(&"device").unwrap(), Some("hdmi"));
+
+        // Dictionaries of different types can't be merged.
+        let other = Dict::new(u32::SIGNATURE, Value::SIGNATURE);
+        let dict = Dict::new(<&str>::SIGNATURE, Value::SIGNATURE);
+        assert!(matches!(
+            dict.merge(other).unwrap_err(),
+            Error::SignatureMismatch(..)
+        ));
+    }
+
     #[test]
     fn value_value() {
         let ctxt = Context::new_dbus(BE, 0);
@@ -2100,7 +2303,117 @@ mod tests {
 
         // TODO:
         //
-        // * Test deserializers.
         // * Test gvariant format.
     }
+
+    #[test]
+    fn deserializer_recursion_limits() {
+        let ctxt = Context::new_dbus(LE, 0);
+        let nested_variants = |depth: usize| {
+            let mut bytes = [1, b'v', 0].repeat(depth - 1);
+            bytes.extend([1, b'y', 0, 42]);
+            bytes
+        };
+
+        // Total container depth is at the limit (64).
+        let bytes = nested_variants(64);
+        let encoded = crate::serialized::Data::new(&bytes[..], ctxt);
+        let (mut value, _) = encoded.deserialize::<Value<'_>>().unwrap();
+        for _ in 0..63 {
+            value = match value {
+                Value::Value(v) => *v,
+                v => panic!("unexpected value: {v:?}"),
+            };
+        }
+        assert_eq!(value, Value::U8(42));
+
+        // Total container depth exceeds limit (64). A hostile peer can make it arbitrarily deep
+        // but decoding is stopped before the stack is exhausted.
+        for depth in [65, 100_000] {
+            let bytes = nested_variants(depth);
+            let encoded = crate::serialized::Data::new(&bytes[..], ctxt);
+            assert!(matches!(
+                encoded.deserialize::<Value<'_>>(),
+                Err(Error::MaxDepthExceeded(MaxDepthExceeded::Container))
+            ));
+        }
+
+        // Array depth within a variant's signature exceeds limit (32).
+        let mut bytes = vec![34];
+        bytes.extend([b'a'; 33]);
+        bytes.extend([b'y', 0, 0, 0, 0, 0, 0]);
+        let encoded = crate::serialized::Data::new(&bytes[..], ctxt);
+        encoded.deserialize::<Value<'_>>().unwrap_err();
+    }
+
+    #[test]
+    fn duration() {
+        use std::time::Duration;
+
+        // Encoded as a structure of seconds and nanoseconds, following serde's representation.
+        assert_eq!(Duration::SIGNATURE, "(tu)");
+        let ctxt = Context::new_dbus(LE, 0);
+        let duration = Duration::new(42, 123_456_789);
+        let encoded = to_bytes(ctxt, &duration).unwrap();
+        assert_eq!(
+            encoded.bytes(),
+            to_bytes(ctxt, &(42u64, 123_456_789u32)).unwrap().bytes()
+        );
+        let decoded: Duration = encoded.deserialize().unwrap().0;
+        assert_eq!(decoded, duration);
+    }
+
+    #[test]
+    fn ip_addr() {
+        use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+        // Encoded as a structure of the variant index (0 for IPv4, 1 for IPv6) and the octets.
+        assert_eq!(IpAddr::SIGNATURE, "(uay)");
+        let ctxt = Context::new_dbus(LE, 0);
+        for addr in [
+            IpAddr::V4(Ipv4Addr::new(192, 168, 1, 42)),
+            IpAddr::V6(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 0x42)),
+        ] {
+            let encoded = to_bytes(ctxt, &addr).unwrap();
+            let octets = match addr {
+                IpAddr::V4(v4) => (0u32, v4.octets().to_vec()),
+                IpAddr::V6(v6) => (1u32, v6.octets().to_vec()),
+            };
+            assert_eq!(encoded.bytes(), to_bytes(ctxt, &octets).unwrap().bytes());
+            let decoded: IpAddr = encoded.deserialize().unwrap().0;
+            assert_eq!(decoded, addr);
+        }
+    }
+
+    #[test]
+    fn array_length_limits() {
+        let ctxt = Context::new_dbus(LE, 0);
+        let max = crate::MAX_ARRAY_LEN;
+
+        // Byte arrays.
+        let path = "a".repeat(max);
+        crate::serialized_size(ctxt, &crate::FilePath::from(&path[..])).unwrap();
+        let path = "a".repeat(max + 1);
+        assert_eq!(
+            crate::serialized_size(ctxt, &crate::FilePath::from(&path[..])).unwrap_err(),
+            Error::ArrayTooLarge(max + 1)
+        );
+
+        // Other arrays, where the length is only known after serializing the elements.
+        let s = "a".repeat(max / 2);
+        let strings = vec![&s[..], &s[..], &s[..]];
+        assert!(matches!(
+            crate::serialized_size(ctxt, &strings),
+            Err(Error::ArrayTooLarge(_))
+        ));
+
+        // Lengths read from the wire must neither exceed the limit nor overflow.
+        let encoded = crate::serialized::Data::new(&[0xff, 0xff, 0xff, 0xff][..], ctxt);
+        assert_eq!(
+            encoded.deserialize::<Vec<u32>>().unwrap_err(),
+            Error::ArrayTooLarge(u32::MAX as usize)
+        );
+        encoded.deserialize::<&str>().unwrap_err();
+        encoded.deserialize::<crate::FilePath<'_>>().unwrap_err();
+    }
 }
@@ -187,6 +187,21 @@ impl<'a> Structure<'a> {
         &self.fields
     }
 
+    /// Get the field at the given index.
+    ///
+    /// The field is converted to `V`, failing if it's of a different type. `Ok(None)` is returned
+    /// if there's no field at `idx`.
+    pub fn get<V>(&'a self, idx: usize) -> crate::Result<Option<V>>
+    where
+        V: TryFrom<&'a Value<'a>>,
+        <V as TryFrom<&'a Value<'a>>>::Error: Into<crate::Error>,
+    {
+        self.fields
+            .get(idx)
+            .map(|v| v.downcast_ref::<V>())
+            .transpose()
+    }
+
     /// Converts `self` to a `Vec` containing all its fields.
     pub fn into_fields(self) -> Vec<Value<'a>> {
         self.fields
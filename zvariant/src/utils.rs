@@ -7,6 +7,10 @@ pub const ARRAY_SIGNATURE_CHAR: char = 'a';
 /// The prefix of ARRAY type signature, as a string. Provided for manual signature creation.
 pub const ARRAY_SIGNATURE_STR: &str = "a";
 pub(crate) const ARRAY_ALIGNMENT_DBUS: usize = 4;
+/// The maximum length of an array in bytes, as per the D-Bus specification.
+///
+/// Encoding or decoding a longer array in the D-Bus format fails with [`Error::ArrayTooLarge`].
+pub const MAX_ARRAY_LEN: usize = 1 << 26;
 /// The opening character of STRUCT type signature. Provided for manual signature creation.
 pub const STRUCT_SIG_START_CHAR: char = '(';
 /// The closing character of STRUCT type signature. Provided for manual signature creation.
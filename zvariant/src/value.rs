@@ -470,6 +470,59 @@ impl<'a> Value<'a> {
         }
         .map_err(Into::into)
     }
+
+    /// Whether `predicate` holds for this value or any value nested in it.
+    ///
+    /// See [`Value::find`] for details on the traversal.
+    pub fn recurse<F>(&self, predicate: F) -> bool
+    where
+        F: Fn(&Value<'_>) -> bool,
+    {
+        self.find(predicate).is_some()
+    }
+
+    /// Find the first value for which `predicate` holds, in this value or any value nested in it.
+    ///
+    /// The search is depth-first, starting with `self`. Values are nested in elements of arrays,
+    /// keys and values of dictionaries, fields of structures, as well as in [`Value::Value`] and
+    /// (with the `gvariant` feature) [`Value::Maybe`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use zvariant::Value;
+    ///
+    /// let props = HashMap::from([("Volume", Value::new(80u8)), ("Muted", Value::new(false))]);
+    /// let props = Value::from(props);
+    /// assert!(props.recurse(|v| matches!(v, Value::Bool(false))));
+    /// assert_eq!(props.find(|v| matches!(v, Value::U8(_))), Some(&Value::U8(80)));
+    /// assert_eq!(props.find(|v| matches!(v, Value::U32(_))), None);
+    /// ```
+    pub fn find<F>(&self, predicate: F) -> Option<&Value<'a>>
+    where
+        F: Fn(&Value<'_>) -> bool,
+    {
+        self.find_(&predicate)
+    }
+
+    fn find_(&self, predicate: &dyn Fn(&Value<'_>) -> bool) -> Option<&Value<'a>> {
+        if predicate(self) {
+            return Some(self);
+        }
+
+        match self {
+            Value::Value(v) => v.find_(predicate),
+            Value::Array(a) => a.inner().iter().find_map(|v| v.find_(predicate)),
+            Value::Dict(d) => d
+                .iter()
+                .find_map(|(k, v)| k.find_(predicate).or_else(|| v.find_(predicate))),
+            Value::Structure(s) => s.fields().iter().find_map(|v| v.find_(predicate)),
+            #[cfg(feature = "gvariant")]
+            Value::Maybe(m) => m.inner().as_ref().and_then(|v| v.find_(predicate)),
+            _ => None,
+        }
+    }
 }
 
 impl Display for Value<'_> {
@@ -1178,4 +1231,31 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn find() {
+        let value = Value::new((
+            1_u8,
+            vec!["a", "b"],
+            HashMap::from([("key", Value::new(Value::new(42_u32)))]),
+        ));
+
+        // Order of traversal is depth-first, starting with the value itself.
+        assert!(matches!(value.find(|_| true), Some(Value::Structure(_))));
+        assert_eq!(
+            value.find(|v| matches!(v, Value::Str(_))),
+            Some(&Value::from("a"))
+        );
+        assert_eq!(
+            value.find(|v| matches!(v, Value::Str(s) if s.as_str() == "key")),
+            Some(&Value::from("key"))
+        );
+        assert_eq!(
+            value.find(|v| matches!(v, Value::U32(_))),
+            Some(&Value::U32(42))
+        );
+        assert!(value.recurse(|v| v == &Value::from("b")));
+        assert!(!value.recurse(|v| v == &Value::from("c")));
+        assert!(!value.recurse(|v| matches!(v, Value::I64(_))));
+    }
 }
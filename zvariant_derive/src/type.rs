@@ -1,9 +1,10 @@
 use std::str::FromStr;
 
-use proc_macro2::TokenStream;
+use proc_macro2::{TokenStream, TokenTree};
 use quote::{quote, ToTokens};
 use syn::{
-    spanned::Spanned, Attribute, Data, DataEnum, DeriveInput, Error, Fields, Generics, Ident,
+    parse_quote, spanned::Spanned, Attribute, Data, DataEnum, DeriveInput, Error, Fields,
+    GenericArgument, Generics, Ident, PathArguments, Type,
 };
 use zvariant_utils::signature::Signature;
 
@@ -57,10 +58,11 @@ pub fn expand_derive(ast: DeriveInput) -> Result<TokenStream, Error> {
 
 fn impl_struct(
     name: Ident,
-    generics: Generics,
+    mut generics: Generics,
     fields: Fields,
     zv: &TokenStream,
 ) -> Result<TokenStream, Error> {
+    add_type_bounds(&mut generics, &fields, zv);
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     let signature = signature_for_struct(&fields, zv, false);
 
@@ -142,7 +144,7 @@ fn impl_empty_struct(
 
 fn impl_enum(
     name: Ident,
-    generics: Generics,
+    mut generics: Generics,
     attrs: Vec<Attribute>,
     data: DataEnum,
     zv: &TokenStream,
@@ -163,6 +165,9 @@ fn impl_enum(
         }
     }
 
+    for variant in &data.variants {
+        add_type_bounds(&mut generics, &variant.fields, zv);
+    }
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     Ok(quote! {
@@ -172,6 +177,92 @@ fn impl_enum(
     })
 }
 
+// The signature is computed from the `Type` implementation of each field so, like serde does for
+// its traits, we require `Type` for each type parameter used in a field. Since a parameter's own
+// bound says nothing about its associated types, paths such as `I::Item` in a field are bounded
+// as a whole as well. Other field types are never bounded, so private ones don't end up in the
+// bounds of the impl.
+fn add_type_bounds(generics: &mut Generics, fields: &Fields, zv: &TokenStream) {
+    fn uses_ident(tokens: TokenStream, ident: &Ident) -> bool {
+        tokens.into_iter().any(|token| match token {
+            TokenTree::Ident(i) => i == *ident,
+            TokenTree::Group(g) => uses_ident(g.stream(), ident),
+            _ => false,
+        })
+    }
+
+    fn collect(ty: &Type, params: &[Ident], used: &mut Vec<Ident>, bounded: &mut Vec<Type>) {
+        match ty {
+            Type::Path(path) => {
+                let first = path.path.segments.first().map(|segment| &segment.ident);
+                let projection = path.qself.is_some()
+                    || (path.path.segments.len() > 1
+                        && first.is_some_and(|ident| params.contains(ident)));
+                if projection {
+                    if !bounded.contains(ty) {
+                        bounded.push(ty.clone());
+                    }
+
+                    return;
+                }
+                if let Some(ident) = path.path.get_ident() {
+                    if params.contains(ident) && !used.contains(ident) {
+                        used.push(ident.clone());
+                    }
+
+                    return;
+                }
+                for segment in &path.path.segments {
+                    if let PathArguments::AngleBracketed(args) = &segment.arguments {
+                        for arg in &args.args {
+                            if let GenericArgument::Type(ty) = arg {
+                                collect(ty, params, used, bounded);
+                            }
+                        }
+                    }
+                }
+            }
+            Type::Reference(reference) => collect(&reference.elem, params, used, bounded),
+            Type::Slice(slice) => collect(&slice.elem, params, used, bounded),
+            Type::Array(array) => collect(&array.elem, params, used, bounded),
+            Type::Paren(paren) => collect(&paren.elem, params, used, bounded),
+            Type::Group(group) => collect(&group.elem, params, used, bounded),
+            Type::Tuple(tuple) => {
+                for elem in &tuple.elems {
+                    collect(elem, params, used, bounded);
+                }
+            }
+            _ => {
+                let tokens = ty.to_token_stream();
+                for ident in params {
+                    if !used.contains(ident) && uses_ident(tokens.clone(), ident) {
+                        used.push(ident.clone());
+                    }
+                }
+            }
+        }
+    }
+
+    let params: Vec<Ident> = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect();
+    let mut used = vec![];
+    let mut bounded = vec![];
+    for field in fields {
+        collect(&field.ty, &params, &mut used, &mut bounded);
+    }
+    let where_clause = generics.make_where_clause();
+    for ident in used {
+        where_clause
+            .predicates
+            .push(parse_quote!(#ident: #zv::Type));
+    }
+    for ty in bounded {
+        where_clause.predicates.push(parse_quote!(#ty: #zv::Type));
+    }
+}
+
 fn signature_for_variant(
     variant: &syn::Variant,
     attrs: &[Attribute],
@@ -82,3 +82,81 @@ fn derive_dict() {
 
     assert_eq!(Test::SIGNATURE, "a{sv}")
 }
+
+#[test]
+fn derive_generic_struct() {
+    use zvariant::{FilePath, Optional};
+
+    #[derive(Type)]
+    struct Entry<T> {
+        name: String,
+        payload: T,
+    }
+
+    #[derive(Type)]
+    struct Wrapper<T>(T);
+
+    #[derive(Type)]
+    struct Pair<'a, K, V>
+    where
+        K: Ord,
+    {
+        key: &'a K,
+        values: Vec<V>,
+    }
+
+    #[derive(Type)]
+    enum Either<L, R> {
+        Left(L, R),
+        Right(L, R),
+    }
+
+    assert_eq!(Entry::<FilePath<'_>>::SIGNATURE, "(say)");
+    assert_eq!(Entry::<Vec<FilePath<'_>>>::SIGNATURE, "(saay)");
+    assert_eq!(Entry::<Optional<FilePath<'_>>>::SIGNATURE, "(say)");
+    assert_eq!(Entry::<Wrapper<FilePath<'_>>>::SIGNATURE, "(say)");
+    assert_eq!(Wrapper::<FilePath<'_>>::SIGNATURE, "ay");
+    assert_eq!(Pair::<u32, FilePath<'_>>::SIGNATURE, "(uaay)");
+    assert_eq!(Either::<u8, FilePath<'_>>::SIGNATURE, "(u(yay))");
+}
+
+#[test]
+fn derive_generic_projection() {
+    // Only the field type needs to implement `Type`, not the type parameter itself.
+    #[derive(Type)]
+    struct Items<I: Iterator>
+    where
+        I::Item: Type,
+    {
+        first: I::Item,
+    }
+
+    #[derive(Type)]
+    enum End<I: Iterator> {
+        First(I::Item),
+        Last(I::Item),
+    }
+
+    assert_eq!(Items::<std::vec::IntoIter<u32>>::SIGNATURE, "(u)");
+    assert_eq!(End::<std::vec::IntoIter<String>>::SIGNATURE, "(us)");
+}
+
+// Deriving `Type` for a public type with a private generic field type must not leak the private type
+// into the bounds of the public impl.
+#[deny(private_bounds)]
+pub mod private_field {
+    use zvariant::Type;
+
+    #[derive(Type)]
+    struct Inner<T>(T, u8);
+
+    #[derive(Type)]
+    pub struct Outer<T> {
+        inner: Inner<T>,
+    }
+}
+
+#[test]
+fn derive_private_generic_field() {
+    assert_eq!(private_field::Outer::<String>::SIGNATURE, "((sy))");
+}
@@ -388,6 +388,59 @@ pub fn validate(bytes: &[u8]) -> Result<(), Error> {
     parse(bytes, true).map(|_| ())
 }
 
+/// The maximum nesting depth of arrays, as per the D-Bus specification.
+pub const MAX_ARRAY_DEPTH: u8 = 32;
+/// The maximum nesting depth of structures, as per the D-Bus specification.
+pub const MAX_STRUCT_DEPTH: u8 = 32;
+/// The maximum nesting depth of all containers combined, as per the D-Bus specification.
+pub const MAX_TOTAL_DEPTH: u8 = 64;
+
+/// The nesting depth of containers at the current position of the parser.
+///
+/// Limiting it keeps the recursion of the parser in check, however deeply nested the input is.
+#[derive(Debug, Default, Clone, Copy)]
+struct Depth {
+    array: u8,
+    structure: u8,
+    total: u8,
+}
+
+impl Depth {
+    fn inc_array(self) -> Option<Self> {
+        Self {
+            array: self.array + 1,
+            total: self.total + 1,
+            ..self
+        }
+        .check()
+    }
+
+    fn inc_structure(self) -> Option<Self> {
+        Self {
+            structure: self.structure + 1,
+            total: self.total + 1,
+            ..self
+        }
+        .check()
+    }
+
+    #[cfg(feature = "gvariant")]
+    fn inc_maybe(self) -> Option<Self> {
+        Self {
+            total: self.total + 1,
+            ..self
+        }
+        .check()
+    }
+
+    fn check(self) -> Option<Self> {
+        (self.array <= MAX_ARRAY_DEPTH
+            && self.structure <= MAX_STRUCT_DEPTH
+            && self.total <= MAX_TOTAL_DEPTH)
+            .then_some(self)
+    }
+}
+
 /// Parse a signature string into a `Signature`.
 ///
 /// When `check_only` is true, the function will not allocate memory for the dynamic types.
@@ -414,8 +467,9 @@ fn parse(bytes: &[u8], check_only: bool) -> Result<Signature, Error> {
         bytes: &[u8],
         check_only: bool,
         top_level: bool,
+        depth: Depth,
     ) -> Result<(&[u8], Signature), ManyError<'_>> {
-        let parser = |s| parse_signature(s, check_only);
+        let parser = |s| parse_signature(s, check_only, depth);
         if check_only {
             return map(many1_count(parser), |_| Signature::Unit)(bytes);
         }
@@ -453,8 +507,30 @@ fn parse(bytes: &[u8], check_only: bool) -> Result<Signature, Error> {
         )(bytes)
     }
 
-    fn parse_signature(bytes: &[u8], check_only: bool) -> nom::IResult<&[u8], Signature> {
-        let parse_with_context = |bytes| parse_signature(bytes, check_only);
+    fn parse_signature(
+        bytes: &[u8],
+        check_only: bool,
+        depth: Depth,
+    ) -> nom::IResult<&[u8], Signature> {
+        // Descend into a container, failing (without backtracking) if it's nested too deep.
+        fn descend<'b, T>(
+            bytes: &'b [u8],
+            depth: Option<Depth>,
+            parser: impl FnOnce(&'b [u8], Depth) -> nom::IResult<&'b [u8], T>,
+        ) -> nom::IResult<&'b [u8], T> {
+            match depth {
+                Some(depth) => parser(bytes, depth),
+                None => Err(nom::Err::Failure(nom::error::Error::new(
+                    bytes,
+                    nom::error::ErrorKind::TooLarge,
+                ))),
+            }
+        }
+        let array_child = |bytes| {
+            descend(bytes, depth.inc_array(), |bytes, depth| {
+                parse_signature(bytes, check_only, depth)
+            })
+        };
 
         let simple_type = alt((
             map(byte(b'y'), |_| Signature::U8),
@@ -477,11 +553,7 @@ fn parse(bytes: &[u8], check_only: bool) -> Result<Signature, Error> {
         let dict = map(
             pair(
                 byte(b'a'),
-                delimited(
-                    byte(b'{'),
-                    pair(parse_with_context, parse_with_context),
-                    byte(b'}'),
-                ),
+                delimited(byte(b'{'), pair(array_child, array_child), byte(b'}')),
             ),
             |(_, (key, value))| {
                 if check_only {
@@ -498,7 +570,7 @@ fn parse(bytes: &[u8], check_only: bool) -> Result<Signature, Error> {
             },
         );
 
-        let array = map(pair(byte(b'a'), parse_with_context), |(_, child)| {
+        let array = map(pair(byte(b'a'), array_child), |(_, child)| {
             if check_only {
                 return Signature::Array(Signature::Unit.into());
             }
@@ -506,16 +578,31 @@ fn parse(bytes: &[u8], check_only: bool) -> Result<Signature, Error> {
             Signature::Array(child.into())
         });
 
-        let structure = delimited(byte(b'('), |s| many(s, check_only, false), byte(b')'));
+        let structure = delimited(
+            byte(b'('),
+            |bytes| {
+                descend(bytes, depth.inc_structure(), |bytes, depth| {
+                    many(bytes, check_only, false, depth)
+                })
+            },
+            byte(b')'),
+        );
 
         #[cfg(feature = "gvariant")]
-        let maybe = map(pair(byte(b'm'), parse_with_context), |(_, child)| {
-            if check_only {
-                return Signature::Maybe(Signature::Unit.into());
-            }
+        let maybe = map(
+            pair(byte(b'm'), |bytes| {
+                descend(bytes, depth.inc_maybe(), |bytes, depth| {
+                    parse_signature(bytes, check_only, depth)
+                })
+            }),
+            |(_, child)| {
+                if check_only {
+                    return Signature::Maybe(Signature::Unit.into());
+                }
 
-            Signature::Maybe(child.into())
-        });
+                Signature::Maybe(child.into())
+            },
+        );
 
         alt((
             simple_type,
@@ -527,8 +614,9 @@ fn parse(bytes: &[u8], check_only: bool) -> Result<Signature, Error> {
         ))(bytes)
     }
 
-    let (_, signature) = all_consuming(alt((empty, |s| many(s, check_only, true))))(bytes)
-        .map_err(|_| Error::InvalidSignature)?;
+    let top_level = |s| many(s, check_only, true, Depth::default());
+    let (_, signature) =
+        all_consuming(alt((empty, top_level)))(bytes).map_err(|_| Error::InvalidSignature)?;
 
     Ok(signature)
 }
@@ -172,3 +172,30 @@ fn test_hash(signature1: &Signature, signature2: &Signature) {
 
     assert_eq!(hash1, hash2);
 }
+
+#[test]
+fn depth_limits() {
+    let arrays = |n| format!("{}y", "a".repeat(n));
+    let structs = |n| format!("{}y{}", "(".repeat(n), ")".repeat(n));
+    let dicts = |n| format!("{}y{}", "a{s".repeat(n), "}".repeat(n));
+
+    assert!(validate(arrays(32).as_bytes()).is_ok());
+    assert!(Signature::from_str(&arrays(32)).is_ok());
+    assert!(validate(arrays(33).as_bytes()).is_err());
+    assert!(Signature::from_str(&arrays(33)).is_err());
+
+    assert!(validate(structs(32).as_bytes()).is_ok());
+    assert!(validate(structs(33).as_bytes()).is_err());
+
+    assert!(validate(dicts(32).as_bytes()).is_ok());
+    assert!(validate(dicts(33).as_bytes()).is_err());
+
+    // 32 levels of each are allowed at the same time.
+    let both = format!("{}{}", "a".repeat(32), structs(32));
+    assert!(validate(both.as_bytes()).is_ok());
+
+    // Parsing a hostile signature doesn't exhaust the stack.
+    let hostile = "a(".repeat(100_000);
+    assert!(validate(hostile.as_bytes()).is_err());
+    assert!(Signature::from_str(&hostile).is_err());
+}